@@ -0,0 +1,36 @@
+pub mod timing;
+pub mod verify;
+
+use aoc_support::solution::Solver;
+
+/// The solution for every day that has been implemented, in day order
+pub const SOLUTIONS: &[&dyn Solver] = &[
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day17::Day17,
+];
+
+/// Find the solution for a day
+pub fn get_solution(day: u32) -> Option<&'static dyn Solver> {
+    SOLUTIONS.iter().copied().find(|s| s.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solutions_are_in_day_order() {
+        assert!(SOLUTIONS.windows(2).all(|w| w[0].day() < w[1].day()));
+    }
+
+    #[test]
+    fn test_get_solution() {
+        assert_eq!(Some(17), get_solution(17).map(|s| s.day()));
+        assert!(get_solution(25).is_none());
+    }
+}
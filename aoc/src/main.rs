@@ -0,0 +1,445 @@
+use aoc::{
+    get_solution,
+    timing::{self, TimingRuns},
+    verify, SOLUTIONS,
+};
+use aoc_support::{
+    answers::AnswerStore,
+    input,
+    parse::ParseError,
+    report::{DayReport, Format, Printer},
+    solution::{format_day, Solver},
+};
+use std::{env, process};
+
+const USAGE: &str = "usage: aoc [all | NN...] [--input <path>] [--format text|json|jsonl]
+       aoc time [all | NN...] [--input <path> | --generate SIZE [--seed N]]
+                [--warmup N] [--runs N] [--format text|json|jsonl]
+       aoc verify [all | NN...]
+       aoc record [all | NN...]";
+
+fn main() {
+    let args: Vec<_> = env::args().skip(1).collect();
+    let options = match Options::parse(&args) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("error: {}\n{}", message, USAGE);
+            process::exit(2);
+        }
+    };
+
+    let all_ok = match options.command {
+        Command::Run(format) => run_days(&options.solvers, format),
+        Command::Time(runs, format) => time_days(&options.solvers, runs, options.generate, format),
+        Command::Verify => verify_days(&options.solvers, false),
+        Command::Record => verify_days(&options.solvers, true),
+    };
+
+    if !all_ok {
+        process::exit(1);
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    /// Solve and print the answers in the given format
+    Run(Format),
+    /// Time each phase of the solutions and print the times in the given
+    /// format
+    Time(TimingRuns, Format),
+    /// Check the answers against the accepted answers
+    Verify,
+    /// Check the answers, and accept those for parts without an accepted
+    /// answer
+    Record,
+}
+
+struct Options {
+    command: Command,
+    solvers: Vec<&'static dyn Solver>,
+    /// Time each day on an input made by its generator instead of its
+    /// puzzle input
+    generate: Option<Generate>,
+}
+
+/// The size of input for each day's generator to make, and the seed to
+/// make it from
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct Generate {
+    size: usize,
+    seed: u64,
+}
+
+impl Options {
+    /// Pick out the command and the solutions for the days named on the
+    /// command line. No days (or `all`) means every day.
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut args = args.iter().peekable();
+        let mut command = match args.peek().map(|a| a.as_str()) {
+            Some("time") => {
+                args.next();
+                Command::Time(TimingRuns::default(), Format::default())
+            }
+            Some("verify") => {
+                args.next();
+                Command::Verify
+            }
+            Some("record") => {
+                args.next();
+                Command::Record
+            }
+            _ => Command::Run(Format::default()),
+        };
+
+        let mut days = vec![];
+        let mut has_input_arg = false;
+        let mut generate_size = None;
+        let mut seed = None;
+        while let Some(arg) = args.next() {
+            match (arg.as_str(), &mut command) {
+                ("--input", _) => {
+                    has_input_arg = true;
+                    args.next();
+                }
+                (arg, _) if arg.starts_with("--input=") => has_input_arg = true,
+                ("--warmup", Command::Time(runs, _)) => runs.warmup = parse_count(args.next())?,
+                ("--runs", Command::Time(runs, _)) => runs.measured = parse_count(args.next())?,
+                ("--generate", Command::Time(..)) => {
+                    generate_size = Some(parse_number("--generate", args.next())?)
+                }
+                ("--seed", Command::Time(..)) => seed = Some(parse_number("--seed", args.next())?),
+                ("--format", Command::Run(format) | Command::Time(_, format)) => {
+                    *format = args.next().map_or("", |a| a.as_str()).parse()?
+                }
+                (arg, Command::Run(format) | Command::Time(_, format))
+                    if arg.starts_with("--format=") =>
+                {
+                    *format = arg["--format=".len()..].parse()?
+                }
+                ("all", _) => days.extend(SOLUTIONS.iter().map(|s| s.day())),
+                (arg, _) => match arg.parse::<u32>() {
+                    Ok(day) => days.push(day),
+                    Err(_) => return Err(format!("{} is not a day", arg)),
+                },
+            }
+        }
+
+        if days.is_empty() {
+            days.extend(SOLUTIONS.iter().map(|s| s.day()));
+        }
+
+        if has_input_arg && days.len() > 1 {
+            return Err(String::from(
+                "--input can only be used when running one day",
+            ));
+        }
+
+        if has_input_arg && generate_size.is_some() {
+            return Err(String::from(
+                "--input and --generate can't be used together",
+            ));
+        }
+        if seed.is_some() && generate_size.is_none() {
+            return Err(String::from("--seed can only be used with --generate"));
+        }
+        let generate = generate_size.map(|size| Generate {
+            size,
+            seed: seed.unwrap_or(0),
+        });
+
+        let solvers = days
+            .iter()
+            .map(|day| get_solution(*day).ok_or(format!("day {} has no solution", day)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            command,
+            solvers,
+            generate,
+        })
+    }
+}
+
+fn parse_count(arg: Option<&String>) -> Result<usize, String> {
+    match arg.map(|a| a.parse::<usize>()) {
+        Some(Ok(count)) => Ok(count),
+        _ => Err(String::from("--warmup and --runs need a number of runs")),
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(option: &str, arg: Option<&String>) -> Result<T, String> {
+    arg.and_then(|a| a.parse().ok())
+        .ok_or_else(|| format!("{} needs a number", option))
+}
+
+/// Read a day's puzzle input, reporting it if it can't be read
+fn load_input(solver: &dyn Solver) -> Option<String> {
+    let day = format_day(solver.day());
+    match input::load(&day, solver.embedded_input()) {
+        Ok(input) => Some(input),
+        Err(e) => {
+            eprintln!("Day {}: {}", day, e);
+            None
+        }
+    }
+}
+
+/// Report a puzzle input that was read but couldn't be parsed
+fn report_parse_error(solver: &dyn Solver, error: &ParseError) {
+    eprintln!(
+        "Day {}: could not parse the puzzle input: {}",
+        format_day(solver.day()),
+        error
+    );
+}
+
+/// Solve both parts of each day, printing the answers as each day finishes
+/// (or, for JSON, once every day has). Returns false if any day couldn't be
+/// run.
+fn run_days(solvers: &[&dyn Solver], format: Format) -> bool {
+    let mut all_ok = true;
+    let mut printer = Printer::new(format);
+    for solver in solvers {
+        let day = format_day(solver.day());
+        let report = match input::load(&day, solver.embedded_input()) {
+            Ok(input) => DayReport::solve(*solver, &input),
+            Err(e) => DayReport::failed(solver.day(), e.to_string()),
+        };
+        all_ok &= report.is_ok();
+        printer.print(&report);
+    }
+    printer.finish();
+    all_ok
+}
+
+/// Make an input for a day with its generator, reporting it if the day
+/// doesn't have one
+fn generate_input(solver: &dyn Solver, generate: Generate) -> Option<String> {
+    let input = solver.generate_input(generate.seed, generate.size);
+    if input.is_none() {
+        eprintln!(
+            "Day {}: there is no input generator",
+            format_day(solver.day())
+        );
+    }
+    input
+}
+
+/// Time the days and print a report of how long each phase took, on their
+/// puzzle inputs or on generated inputs. Days without an input are left out
+/// of the report. The text report is a table printed once every day has been
+/// timed; JSON is printed as for answers.
+fn time_days(
+    solvers: &[&dyn Solver],
+    runs: TimingRuns,
+    generate: Option<Generate>,
+    format: Format,
+) -> bool {
+    let mut all_ok = true;
+    let mut timings = vec![];
+    let mut printer = Printer::new(format);
+    for solver in solvers {
+        let input = match generate {
+            Some(generate) => generate_input(*solver, generate),
+            None => load_input(*solver),
+        };
+        match input {
+            Some(input) => match timing::time_day(*solver, &input, runs) {
+                Ok(day_timings) => {
+                    if format != Format::Text {
+                        printer.print_json(day_timings.to_json());
+                    }
+                    timings.push(day_timings)
+                }
+                Err(e) => {
+                    report_parse_error(*solver, &e);
+                    all_ok = false;
+                }
+            },
+            None => all_ok = false,
+        }
+    }
+
+    if format == Format::Text {
+        print!("{}", timing::format_report(&timings));
+    }
+    printer.finish();
+    all_ok
+}
+
+/// Compare the days' answers with the accepted answers and print a report.
+/// When `record` is set, answers to parts that have no accepted answer are
+/// saved as accepted. Returns false if any answer differs from the accepted
+/// one, or a day or part couldn't be solved.
+fn verify_days(solvers: &[&dyn Solver], record: bool) -> bool {
+    let path = AnswerStore::default_path();
+    let mut store = match AnswerStore::load(&path) {
+        Ok(store) => store,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+
+    let mut all_ok = true;
+    let mut results = vec![];
+    for solver in solvers {
+        match load_input(*solver) {
+            Some(input) => match verify::verify_day(*solver, &input, &store) {
+                Ok(day_results) => results.extend(day_results),
+                Err(e) => {
+                    report_parse_error(*solver, &e);
+                    all_ok = false;
+                }
+            },
+            None => all_ok = false,
+        }
+    }
+
+    print!("{}", verify::format_report(&results));
+
+    let missing: Vec<_> = results
+        .iter()
+        .filter(|r| r.status() == verify::Status::Missing)
+        .collect();
+    if record && !missing.is_empty() {
+        for result in missing {
+            // a part is only missing once it has an answer
+            let answer = result.actual.clone().unwrap();
+            store.insert(result.day, result.part, answer);
+        }
+        match store.save(&path) {
+            Ok(()) => println!("Recorded the missing answers in {}", path.display()),
+            Err(e) => {
+                eprintln!("{}", e);
+                all_ok = false;
+            }
+        }
+    }
+
+    all_ok
+        && results
+            .iter()
+            .all(|r| !matches!(r.status(), verify::Status::Fail | verify::Status::Error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_options(args: &str) -> Result<(Command, Vec<u32>), String> {
+        let args: Vec<_> = args.split_ascii_whitespace().map(String::from).collect();
+        Options::parse(&args).map(|options| {
+            (
+                options.command,
+                options.solvers.iter().map(|s| s.day()).collect(),
+            )
+        })
+    }
+
+    #[test]
+    fn test_parse_options() {
+        let all_days = vec![1, 2, 3, 4, 5, 6, 17];
+        assert_eq!(
+            Ok((Command::Run(Format::Text), all_days.clone())),
+            parse_options("")
+        );
+        assert_eq!(
+            Ok((Command::Run(Format::Text), all_days.clone())),
+            parse_options("all")
+        );
+        assert_eq!(
+            Ok((Command::Run(Format::Text), vec![17, 2])),
+            parse_options("17 02")
+        );
+        assert_eq!(
+            Ok((Command::Run(Format::Text), vec![3])),
+            parse_options("3 --input my_input.txt")
+        );
+        assert_eq!(
+            Ok((
+                Command::Time(TimingRuns::default(), Format::Text),
+                all_days.clone()
+            )),
+            parse_options("time")
+        );
+        assert_eq!(
+            Ok((Command::Time(TimingRuns::default(), Format::Json), vec![6])),
+            parse_options("time --format json 6")
+        );
+        assert_eq!(
+            Ok((
+                Command::Time(TimingRuns::default(), Format::JsonLines),
+                vec![6]
+            )),
+            parse_options("time 6 --format=jsonl")
+        );
+        assert_eq!(
+            Ok((
+                Command::Time(
+                    TimingRuns {
+                        warmup: 0,
+                        measured: 100
+                    },
+                    Format::Text
+                ),
+                vec![5]
+            )),
+            parse_options("time 5 --warmup 0 --runs 100")
+        );
+        assert_eq!(
+            Ok((Command::Verify, all_days.clone())),
+            parse_options("verify")
+        );
+        assert_eq!(Ok((Command::Record, vec![1])), parse_options("record 1"));
+        assert_eq!(
+            Err(String::from("day 25 has no solution")),
+            parse_options("25")
+        );
+        assert_eq!(Err(String::from("x is not a day")), parse_options("x"));
+        assert_eq!(
+            Err(String::from("--runs is not a day")),
+            parse_options("--runs 3")
+        );
+        assert!(parse_options("time --runs x").is_err());
+        assert!(parse_options("1 2 --input=my_input.txt").is_err());
+    }
+
+    #[test]
+    fn test_parse_generate_options() {
+        let generate = |args: &str| {
+            let args: Vec<_> = args.split_ascii_whitespace().map(String::from).collect();
+            Options::parse(&args).map(|options| options.generate)
+        };
+        assert_eq!(Ok(None), generate("time 5"));
+        assert_eq!(
+            Ok(Some(Generate {
+                size: 1000,
+                seed: 0
+            })),
+            generate("time --generate 1000")
+        );
+        assert_eq!(
+            Ok(Some(Generate { size: 50, seed: 7 })),
+            generate("time 17 --seed 7 --generate 50")
+        );
+        assert_eq!(
+            Err(String::from("--generate needs a number")),
+            generate("time --generate lots")
+        );
+        assert_eq!(
+            Err(String::from("--seed can only be used with --generate")),
+            generate("time --seed 7")
+        );
+        assert_eq!(
+            Err(String::from(
+                "--input and --generate can't be used together"
+            )),
+            generate("time 1 --input my_input.txt --generate 10")
+        );
+        assert_eq!(
+            Err(String::from("--generate is not a day")),
+            generate("--generate 10")
+        );
+    }
+}
@@ -0,0 +1,310 @@
+use aoc_support::{
+    parse::ParseError,
+    solution::{format_day, Solver},
+};
+use std::{
+    fmt::Write,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+/// How many times to run each day when timing it
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct TimingRuns {
+    /// Runs made before timing starts, to warm up caches and the allocator
+    pub warmup: usize,
+    /// Runs that are timed
+    pub measured: usize,
+}
+
+impl Default for TimingRuns {
+    fn default() -> Self {
+        Self {
+            warmup: 1,
+            measured: 10,
+        }
+    }
+}
+
+/// The times taken by each measured run of a day
+pub struct DayTimings {
+    pub day: u32,
+    pub parse: Vec<Duration>,
+    pub part1: Vec<Duration>,
+    pub part2: Vec<Duration>,
+}
+
+impl DayTimings {
+    /// The time taken by each run from parsing through to solving part 2
+    pub fn totals(&self) -> Vec<Duration> {
+        (0..self.parse.len())
+            .map(|run| self.parse[run] + self.part1[run] + self.part2[run])
+            .collect()
+    }
+
+    /// An object per phase, as in a row of the report, with the times in
+    /// nanoseconds
+    pub fn to_json(&self) -> Vec<String> {
+        [
+            ("parse", Stats::new(&self.parse)),
+            ("part1", Stats::new(&self.part1)),
+            ("part2", Stats::new(&self.part2)),
+            ("total", Stats::new(&self.totals())),
+        ]
+        .iter()
+        .map(|(phase, stats)| {
+            format!(
+                r#"{{"day":{},"phase":"{}","runs":{},"min_ns":{},"median_ns":{},"max_ns":{}}}"#,
+                self.day,
+                phase,
+                self.parse.len(),
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.max.as_nanos()
+            )
+        })
+        .collect()
+    }
+}
+
+/// Summary of the times taken by a phase over all measured runs
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn new(times: &[Duration]) -> Self {
+        if times.is_empty() {
+            return Self::default();
+        }
+
+        let mut times = times.to_vec();
+        times.sort();
+        let middle = times.len() / 2;
+        let median = if times.len().is_multiple_of(2) {
+            (times[middle - 1] + times[middle]) / 2
+        } else {
+            times[middle]
+        };
+
+        Self {
+            min: times[0],
+            median,
+            max: times[times.len() - 1],
+        }
+    }
+}
+
+/// Time parsing and solving both parts of `input`
+pub fn time_day(
+    solver: &dyn Solver,
+    input: &str,
+    runs: TimingRuns,
+) -> Result<DayTimings, ParseError> {
+    // a part that can't be solved is timed all the same, as running the day
+    // reports why
+    for _ in 0..runs.warmup {
+        let parsed_input = solver.parse(input)?;
+        let _ = black_box(solver.part1(parsed_input.as_ref()));
+        let _ = black_box(solver.part2(parsed_input.as_ref()));
+    }
+
+    let mut timings = DayTimings {
+        day: solver.day(),
+        parse: vec![],
+        part1: vec![],
+        part2: vec![],
+    };
+
+    for _ in 0..runs.measured {
+        let start = Instant::now();
+        let parsed_input = solver.parse(black_box(input))?;
+        timings.parse.push(start.elapsed());
+
+        let start = Instant::now();
+        let _ = black_box(solver.part1(parsed_input.as_ref()));
+        timings.part1.push(start.elapsed());
+
+        let start = Instant::now();
+        let _ = black_box(solver.part2(parsed_input.as_ref()));
+        timings.part2.push(start.elapsed());
+    }
+
+    Ok(timings)
+}
+
+/// Write the times as a table with a row per phase of each day, followed
+/// by the total over all days
+pub fn format_report(timings: &[DayTimings]) -> String {
+    let mut report = String::new();
+    let mut write_row = |label: &str, phase: &str, stats: &Stats| {
+        writeln!(
+            report,
+            "{:<6} {:<7} {:>10} {:>10} {:>10}",
+            label,
+            phase,
+            format_duration(stats.min),
+            format_duration(stats.median),
+            format_duration(stats.max)
+        )
+        .unwrap();
+    };
+
+    let mut all_days = Stats::default();
+    for day_timings in timings {
+        let label = format!("Day {}", format_day(day_timings.day));
+        write_row(&label, "parse", &Stats::new(&day_timings.parse));
+        write_row(&label, "part 1", &Stats::new(&day_timings.part1));
+        write_row(&label, "part 2", &Stats::new(&day_timings.part2));
+
+        let total = Stats::new(&day_timings.totals());
+        write_row(&label, "total", &total);
+
+        all_days.min += total.min;
+        all_days.median += total.median;
+        all_days.max += total.max;
+    }
+    write_row("All", "total", &all_days);
+
+    format!(
+        "{:<6} {:<7} {:>10} {:>10} {:>10}\n{}",
+        "Day", "Phase", "Min", "Median", "Max", report
+    )
+}
+
+/// Format a duration in whichever unit keeps it readable
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.2}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_support::test_support::TestCase;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn test_stats() {
+        let test_cases = [
+            TestCase {
+                input: vec![ms(3), ms(1), ms(2)],
+                expected: Stats {
+                    min: ms(1),
+                    median: ms(2),
+                    max: ms(3),
+                },
+            },
+            TestCase {
+                input: vec![ms(4), ms(1), ms(2), ms(10)],
+                expected: Stats {
+                    min: ms(1),
+                    median: ms(3),
+                    max: ms(10),
+                },
+            },
+            TestCase {
+                input: vec![],
+                expected: Stats::default(),
+            },
+        ];
+        for TestCase { input, expected } in test_cases.iter() {
+            assert_eq!(Stats::new(input), *expected);
+        }
+    }
+
+    #[test]
+    fn test_format_duration() {
+        let test_cases = [
+            TestCase {
+                input: Duration::from_nanos(999),
+                expected: "999ns",
+            },
+            TestCase {
+                input: Duration::from_nanos(12_345),
+                expected: "12.35µs",
+            },
+            TestCase {
+                input: ms(250),
+                expected: "250.00ms",
+            },
+            TestCase {
+                input: ms(1_500),
+                expected: "1.50s",
+            },
+        ];
+        for TestCase { input, expected } in test_cases.iter() {
+            assert_eq!(format_duration(*input), *expected);
+        }
+    }
+
+    #[test]
+    fn test_time_day() {
+        let solver = crate::get_solution(6).unwrap();
+        let runs = TimingRuns {
+            warmup: 1,
+            measured: 3,
+        };
+        let timings = time_day(solver, solver.embedded_input().unwrap(), runs).unwrap();
+        assert_eq!(6, timings.day);
+        assert_eq!(3, timings.parse.len());
+        assert_eq!(3, timings.part1.len());
+        assert_eq!(3, timings.part2.len());
+        assert_eq!(3, timings.totals().len());
+
+        assert!(time_day(solver, "Time: 7\nDistance: x", runs).is_err());
+    }
+
+    #[test]
+    fn test_to_json() {
+        let timings = DayTimings {
+            day: 6,
+            parse: vec![ms(1), ms(3)],
+            part1: vec![ms(2), ms(2)],
+            part2: vec![ms(3), ms(5)],
+        };
+        assert_eq!(
+            vec![
+                r#"{"day":6,"phase":"parse","runs":2,"min_ns":1000000,"median_ns":2000000,"max_ns":3000000}"#,
+                r#"{"day":6,"phase":"part1","runs":2,"min_ns":2000000,"median_ns":2000000,"max_ns":2000000}"#,
+                r#"{"day":6,"phase":"part2","runs":2,"min_ns":3000000,"median_ns":4000000,"max_ns":5000000}"#,
+                r#"{"day":6,"phase":"total","runs":2,"min_ns":6000000,"median_ns":8000000,"max_ns":10000000}"#,
+            ],
+            timings.to_json()
+        );
+    }
+
+    #[test]
+    fn test_format_report() {
+        let timings = [DayTimings {
+            day: 6,
+            parse: vec![ms(1), ms(3)],
+            part1: vec![ms(2), ms(2)],
+            part2: vec![ms(3), ms(5)],
+        }];
+        assert_eq!(
+            "Day    Phase          Min     Median        Max
+Day 06 parse       1.00ms     2.00ms     3.00ms
+Day 06 part 1      2.00ms     2.00ms     2.00ms
+Day 06 part 2      3.00ms     4.00ms     5.00ms
+Day 06 total       6.00ms     8.00ms    10.00ms
+All    total       6.00ms     8.00ms    10.00ms
+",
+            format_report(&timings)
+        );
+    }
+}
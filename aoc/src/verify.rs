@@ -0,0 +1,208 @@
+use aoc_support::{
+    answer::Answer,
+    answers::AnswerStore,
+    parse::ParseError,
+    solution::{format_day, SolveError, Solver},
+};
+use std::fmt::{self, Write};
+
+/// How a part's answer compares with the accepted one
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Status {
+    /// The answer matches the accepted answer
+    Pass,
+    /// The answer differs from the accepted answer
+    Fail,
+    /// There is no accepted answer for the part yet
+    Missing,
+    /// The part couldn't be solved
+    Error,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail => write!(f, "FAIL"),
+            Status::Missing => write!(f, "missing"),
+            Status::Error => write!(f, "ERROR"),
+        }
+    }
+}
+
+/// The answer to one part of a day, next to the accepted answer
+#[derive(Debug, PartialEq)]
+pub struct PartResult {
+    pub day: u32,
+    pub part: u32,
+    pub expected: Option<Answer>,
+    pub actual: Result<Answer, SolveError>,
+}
+
+impl PartResult {
+    pub fn status(&self) -> Status {
+        match (&self.expected, &self.actual) {
+            (_, Err(_)) => Status::Error,
+            (Some(expected), Ok(actual)) if expected == actual => Status::Pass,
+            (Some(_), Ok(_)) => Status::Fail,
+            (None, Ok(_)) => Status::Missing,
+        }
+    }
+}
+
+/// Solve both parts of `input` and pair each answer with the accepted one
+pub fn verify_day(
+    solver: &dyn Solver,
+    input: &str,
+    store: &AnswerStore,
+) -> Result<Vec<PartResult>, ParseError> {
+    let day = solver.day();
+    let parsed_input = solver.parse(input)?;
+    let answers = [
+        solver.part1(parsed_input.as_ref()),
+        solver.part2(parsed_input.as_ref()),
+    ];
+
+    Ok(answers
+        .into_iter()
+        .zip(1..)
+        .map(|(actual, part)| PartResult {
+            day,
+            part,
+            expected: store.get(day, part).cloned(),
+            actual,
+        })
+        .collect())
+}
+
+/// Write the results as a table with a row per part, followed by a count of
+/// each status. A part that couldn't be solved has no answer, and why is
+/// given after its status.
+pub fn format_report(results: &[PartResult]) -> String {
+    let mut report = format!(
+        "{:<6} {:<4} {:>16} {:>16} {}\n",
+        "Day", "Part", "Expected", "Actual", "Result"
+    );
+
+    for result in results {
+        let expected = result
+            .expected
+            .as_ref()
+            .map_or(String::from("-"), |e| e.to_string());
+        let (actual, error) = match &result.actual {
+            Ok(actual) => (actual.to_string(), String::new()),
+            Err(e) => (String::from("-"), format!(": {}", e)),
+        };
+        writeln!(
+            report,
+            "{:<6} {:<4} {:>16} {:>16} {}{}",
+            format!("Day {}", format_day(result.day)),
+            result.part,
+            expected,
+            actual,
+            result.status(),
+            error
+        )
+        .unwrap();
+    }
+
+    let count = |status| results.iter().filter(|r| r.status() == status).count();
+    writeln!(
+        report,
+        "{} passed, {} failed, {} missing, {} errors",
+        count(Status::Pass),
+        count(Status::Fail),
+        count(Status::Missing),
+        count(Status::Error)
+    )
+    .unwrap();
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_support::test_support::TestCase;
+
+    fn result(part: u32, expected: Option<i32>, actual: i32) -> PartResult {
+        PartResult {
+            day: 6,
+            part,
+            expected: expected.map(Answer::from),
+            actual: Ok(Answer::from(actual)),
+        }
+    }
+
+    #[test]
+    fn test_status() {
+        let test_cases = [
+            TestCase {
+                input: result(1, Some(288), 288),
+                expected: Status::Pass,
+            },
+            TestCase {
+                input: result(1, Some(288), 289),
+                expected: Status::Fail,
+            },
+            TestCase {
+                input: result(1, None, 288),
+                expected: Status::Missing,
+            },
+            TestCase {
+                input: PartResult {
+                    actual: Err(SolveError::new("too big")),
+                    ..result(1, Some(288), 288)
+                },
+                expected: Status::Error,
+            },
+        ];
+        for TestCase { input, expected } in test_cases.iter() {
+            assert_eq!(input.status(), *expected);
+        }
+    }
+
+    #[test]
+    fn test_verify_day() {
+        let solver = crate::get_solution(6).unwrap();
+        let mut store = AnswerStore::default();
+        store.insert(6, 1, Answer::from(288));
+        let input = "Time:      7  15   30\nDistance:  9  40  200";
+
+        let results = verify_day(solver, input, &store).unwrap();
+        assert_eq!(
+            vec![result(1, Some(288), 288), result(2, None, 71503)],
+            results
+        );
+    }
+
+    #[test]
+    fn test_format_report() {
+        let results = [result(1, Some(288), 288), result(2, Some(71503), 71502)];
+        assert_eq!(
+            "Day    Part         Expected           Actual Result
+Day 06 1                 288              288 pass
+Day 06 2               71503            71502 FAIL
+1 passed, 1 failed, 0 missing, 0 errors
+",
+            format_report(&results)
+        );
+        assert_eq!(
+            "Day    Part         Expected           Actual Result
+Day 06 2                   -            71503 missing
+0 passed, 0 failed, 1 missing, 0 errors
+",
+            format_report(&[result(2, None, 71503)])
+        );
+        let error = PartResult {
+            actual: Err(SolveError::new("the product doesn't fit in a u64")),
+            ..result(1, Some(288), 288)
+        };
+        assert_eq!(
+            "Day    Part         Expected           Actual Result
+Day 06 1                 288                - ERROR: the product doesn't fit in a u64
+0 passed, 0 failed, 0 missing, 1 errors
+",
+            format_report(&[error])
+        );
+    }
+}
@@ -0,0 +1,247 @@
+use std::{convert::Infallible, fmt, str::FromStr};
+
+/// The answer to one part of a puzzle. Puzzles have integer answers that
+/// can be wider than an `i32` (and occasionally text answers), so this
+/// holds any of them without the solution having to cast.
+#[derive(Debug, Clone, Eq)]
+pub enum Answer {
+    Signed(i128),
+    Unsigned(u128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(value) => fmt::Display::fmt(value, f),
+            Answer::Unsigned(value) => fmt::Display::fmt(value, f),
+            Answer::Text(value) => f.pad(value),
+        }
+    }
+}
+
+/// Integer answers are equal if they have the same value, whether they are
+/// held as signed or unsigned.
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Signed(a), Answer::Signed(b)) => a == b,
+            (Answer::Unsigned(a), Answer::Unsigned(b)) => a == b,
+            (Answer::Signed(a), Answer::Unsigned(b)) | (Answer::Unsigned(b), Answer::Signed(a)) => {
+                u128::try_from(*a) == Ok(*b)
+            }
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+/// Parse an answer as it was printed, e.g. one that was saved after being
+/// accepted. Anything that isn't an integer is a text answer.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Ok(value) = s.parse::<i128>() {
+            Ok(Answer::Signed(value))
+        } else if let Ok(value) = s.parse::<u128>() {
+            Ok(Answer::Unsigned(value))
+        } else {
+            Ok(Answer::Text(String::from(s)))
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(String::from(value))
+    }
+}
+
+/// An answer that can't be converted to the requested type
+#[derive(Debug, PartialEq)]
+pub enum AnswerError {
+    /// The answer is an integer that doesn't fit in the requested type
+    Overflow { answer: Answer, to: &'static str },
+    /// The answer is text, not an integer
+    NotAnInteger(String),
+}
+
+impl fmt::Display for AnswerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswerError::Overflow { answer, to } => {
+                write!(f, "answer {} does not fit in {}", answer, to)
+            }
+            AnswerError::NotAnInteger(text) => write!(f, "answer {:?} is not an integer", text),
+        }
+    }
+}
+
+impl std::error::Error for AnswerError {}
+
+macro_rules! impl_integer_answer {
+    ($variant:ident, $wide:ty, $($t:ty),+) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::$variant(<$wide>::from(value))
+                }
+            }
+
+            impl TryFrom<&Answer> for $t {
+                type Error = AnswerError;
+
+                fn try_from(answer: &Answer) -> Result<Self, Self::Error> {
+                    let overflow = || AnswerError::Overflow {
+                        answer: answer.clone(),
+                        to: stringify!($t),
+                    };
+                    match answer {
+                        Answer::Signed(value) => <$t>::try_from(*value).map_err(|_| overflow()),
+                        Answer::Unsigned(value) => <$t>::try_from(*value).map_err(|_| overflow()),
+                        Answer::Text(text) => Err(AnswerError::NotAnInteger(text.clone())),
+                    }
+                }
+            }
+
+        )+
+        impl_integer_eq!($($t),+);
+    };
+}
+
+/// Lets an answer be compared directly against an integer, e.g. in tests
+macro_rules! impl_integer_eq {
+    ($($t:ty),+) => {
+        $(
+            impl PartialEq<$t> for Answer {
+                fn eq(&self, other: &$t) -> bool {
+                    match self {
+                        Answer::Signed(value) => i128::try_from(*other) == Ok(*value),
+                        Answer::Unsigned(value) => u128::try_from(*other) == Ok(*value),
+                        Answer::Text(_) => false,
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_integer_answer!(Signed, i128, i8, i16, i32, i64, i128);
+impl_integer_answer!(Unsigned, u128, u8, u16, u32, u64, u128);
+impl_integer_eq!(isize, usize);
+
+// usize and isize have no lossless `From` into the 128 bit types, but they
+// are never wider than 64 bits
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Unsigned(value as u128)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Answer::Signed(value as i128)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_support::test_support::TestCase;
+
+    #[test]
+    fn test_display() {
+        let test_cases = [
+            TestCase {
+                input: Answer::from(-142),
+                expected: "-142",
+            },
+            TestCase {
+                input: Answer::from(u128::MAX),
+                expected: "340282366920938463463374607431768211455",
+            },
+            TestCase {
+                input: Answer::from("LJKFH"),
+                expected: "LJKFH",
+            },
+        ];
+        for TestCase { input, expected } in test_cases.iter() {
+            assert_eq!(input.to_string(), *expected);
+        }
+        assert_eq!("  142", format!("{:>5}", Answer::from(142)));
+        assert_eq!("ab   ", format!("{:<5}", Answer::from("ab")));
+    }
+
+    #[test]
+    fn test_eq() {
+        assert_eq!(Answer::from(5_i32), Answer::from(5_u8));
+        assert_eq!(Answer::from(5_usize), Answer::from(5_i64));
+        assert_ne!(Answer::from(-1_i32), Answer::from(u128::MAX));
+        assert_ne!(Answer::from(5), Answer::from("5"));
+        assert_eq!(Answer::from(142_u64), 142);
+        assert_eq!(Answer::from(142), 142_usize);
+    }
+
+    #[test]
+    fn test_from_str() {
+        let test_cases = [
+            TestCase {
+                input: "142",
+                expected: Answer::Signed(142),
+            },
+            TestCase {
+                input: "-5\n",
+                expected: Answer::Signed(-5),
+            },
+            TestCase {
+                input: "340282366920938463463374607431768211455",
+                expected: Answer::Unsigned(u128::MAX),
+            },
+            TestCase {
+                input: "LJKFH",
+                expected: Answer::Text(String::from("LJKFH")),
+            },
+        ];
+        for TestCase { input, expected } in test_cases.iter() {
+            assert_eq!(input.parse::<Answer>(), Ok(expected.clone()));
+        }
+    }
+
+    #[test]
+    fn test_try_from() {
+        assert_eq!(Ok(142), i32::try_from(&Answer::from(142_u64)));
+        assert_eq!(Ok(u32::MAX), u32::try_from(&Answer::from(u32::MAX as i64)));
+        assert_eq!(
+            Err(AnswerError::Overflow {
+                answer: Answer::from(u32::MAX),
+                to: "i32"
+            }),
+            i32::try_from(&Answer::from(u32::MAX))
+        );
+        assert_eq!(
+            Err(AnswerError::Overflow {
+                answer: Answer::from(-1),
+                to: "u64"
+            }),
+            u64::try_from(&Answer::from(-1))
+        );
+        assert_eq!(
+            Err(AnswerError::NotAnInteger(String::from("LJKFH"))),
+            i64::try_from(&Answer::from("LJKFH"))
+        );
+        assert_eq!(
+            "answer 4294967295 does not fit in i32",
+            i32::try_from(&Answer::from(u32::MAX))
+                .unwrap_err()
+                .to_string()
+        );
+    }
+}
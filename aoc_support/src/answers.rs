@@ -0,0 +1,298 @@
+use crate::{answer::Answer, input};
+use std::{collections::BTreeMap, fmt::Write, fs, io, path::PathBuf};
+
+/// The file accepted answers are kept in, alongside the puzzle inputs
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// The accepted answers to each part of each day, so that a refactor that
+/// changes an answer can be caught. Saved as a small TOML file:
+///
+/// ```toml
+/// [day01]
+/// part1 = 142
+/// part2 = 281
+/// ```
+#[derive(Debug, Default, PartialEq)]
+pub struct AnswerStore {
+    answers: BTreeMap<(u32, u32), Answer>,
+}
+
+impl AnswerStore {
+    /// Where the answers are kept: `answers.toml` in the directory named by
+    /// `AOC_INPUT_DIR`, or the workspace's `inputs` directory
+    pub fn default_path() -> PathBuf {
+        let input_dir = std::env::var(input::INPUT_DIR_ENV)
+            .map(PathBuf::from)
+            .unwrap_or_else(|_| input::default_input_dir());
+        input_dir.join(ANSWERS_FILE)
+    }
+
+    /// Read the answers saved at `path`. A missing file is an empty store.
+    pub fn load(path: &PathBuf) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|e| format!("{}: {}", path.display(), e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("could not read {}: {}", path.display(), e)),
+        }
+    }
+
+    pub fn save(&self, path: &PathBuf) -> Result<(), String> {
+        fs::write(path, self.to_toml())
+            .map_err(|e| format!("could not write {}: {}", path.display(), e))
+    }
+
+    pub fn get(&self, day: u32, part: u32) -> Option<&Answer> {
+        self.answers.get(&(day, part))
+    }
+
+    pub fn insert(&mut self, day: u32, part: u32, answer: Answer) {
+        self.answers.insert((day, part), answer);
+    }
+
+    fn parse(text: &str) -> Result<Self, String> {
+        let mut store = Self::default();
+        let mut day = None;
+
+        for (index, line) in text.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                day = header
+                    .strip_prefix("day")
+                    .and_then(|d| d.parse::<u32>().ok())
+                    .map(Some)
+                    .ok_or(format!(
+                        "line {}: expected a [dayNN] header, found {}",
+                        line_number, line
+                    ))?;
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or(format!("line {}: expected partN = answer", line_number))?;
+            let part = match key.trim() {
+                "part1" => 1,
+                "part2" => 2,
+                key => {
+                    return Err(format!(
+                        "line {}: expected part1 or part2, found {}",
+                        line_number, key
+                    ))
+                }
+            };
+            let day = day.ok_or(format!(
+                "line {}: answer comes before any [dayNN] header",
+                line_number
+            ))?;
+            let answer =
+                parse_value(value.trim()).map_err(|e| format!("line {}: {}", line_number, e))?;
+            store.insert(day, part, answer);
+        }
+
+        Ok(store)
+    }
+
+    fn to_toml(&self) -> String {
+        let mut toml = String::new();
+        let mut last_day = None;
+        for ((day, part), answer) in self.answers.iter() {
+            if last_day != Some(*day) {
+                if last_day.is_some() {
+                    toml.push('\n');
+                }
+                writeln!(toml, "[day{:02}]", day).unwrap();
+                last_day = Some(*day);
+            }
+            writeln!(toml, "part{} = {}", part, format_value(answer)).unwrap();
+        }
+        toml
+    }
+}
+
+/// Write `answer` as a value that `parse_value` reads back as the same
+/// answer: text is quoted, so text that looks like a number stays text,
+/// and numbers are written as they are printed
+pub fn format_value(answer: &Answer) -> String {
+    match answer {
+        Answer::Text(text) => quote(text),
+        answer => answer.to_string(),
+    }
+}
+
+/// A quoted value is a text answer; anything else is read the way an
+/// answer is printed
+pub fn parse_value(value: &str) -> Result<Answer, String> {
+    match value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+        Some(text) => unquote(text).map(Answer::Text),
+        None => Ok(value.parse().unwrap()),
+    }
+}
+
+/// Quote `text` as a TOML basic string, escaping the characters that
+/// `unquote` reads back
+fn quote(text: &str) -> String {
+    let mut quoted = String::from('"');
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            '\u{8}' => quoted.push_str("\\b"),
+            '\u{c}' => quoted.push_str("\\f"),
+            c if c.is_control() => write!(quoted, "\\u{:04X}", c as u32).unwrap(),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Undo the escapes in the contents of a TOML basic string
+fn unquote(text: &str) -> Result<String, String> {
+    let mut unquoted = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unquoted.push(c);
+            continue;
+        }
+        let escaped = match chars.next() {
+            Some('"') => '"',
+            Some('\\') => '\\',
+            Some('n') => '\n',
+            Some('r') => '\r',
+            Some('t') => '\t',
+            Some('b') => '\u{8}',
+            Some('f') => '\u{c}',
+            Some(u @ ('u' | 'U')) => {
+                let digits = if u == 'u' { 4 } else { 8 };
+                let hex: String = chars.by_ref().take(digits).collect();
+                Some(&hex)
+                    .filter(|hex| hex.len() == digits && hex.chars().all(|c| c.is_ascii_hexdigit()))
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .and_then(char::from_u32)
+                    .ok_or(format!("\\{}{} is not a character", u, hex))?
+            }
+            Some(c) => return Err(format!("\\{} is not an escape", c)),
+            None => return Err(String::from("the text ends in a \\")),
+        };
+        unquoted.push(escaped);
+    }
+    Ok(unquoted)
+}
+
+#[cfg(test)]
+mod tests {
+    const TEST_ANSWERS: &str = r#"# accepted answers
+[day01]
+part1 = 142
+part2 = 281
+
+[day05]
+part1 = 35
+
+[day17]
+part2 = "LJKFH"
+"#;
+
+    use super::*;
+
+    fn get_test_store() -> AnswerStore {
+        let mut store = AnswerStore::default();
+        store.insert(1, 1, Answer::from(142));
+        store.insert(1, 2, Answer::from(281));
+        store.insert(5, 1, Answer::from(35));
+        store.insert(17, 2, Answer::from("LJKFH"));
+        store
+    }
+
+    #[test]
+    fn test_parse() {
+        let store = AnswerStore::parse(TEST_ANSWERS).unwrap();
+        assert_eq!(get_test_store(), store);
+        assert_eq!(Some(&Answer::from(281)), store.get(1, 2));
+        assert_eq!(None, store.get(5, 2));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Err(String::from(
+                "line 1: answer comes before any [dayNN] header"
+            )),
+            AnswerStore::parse("part1 = 3")
+        );
+        assert_eq!(
+            Err(String::from("line 2: expected part1 or part2, found part3")),
+            AnswerStore::parse("[day01]\npart3 = 3")
+        );
+        assert_eq!(
+            Err(String::from(
+                "line 1: expected a [dayNN] header, found [dependencies]"
+            )),
+            AnswerStore::parse("[dependencies]")
+        );
+        assert_eq!(
+            Err(String::from("line 2: expected partN = answer")),
+            AnswerStore::parse("[day01]\n142")
+        );
+    }
+
+    #[test]
+    fn test_to_toml() {
+        let toml = get_test_store().to_toml();
+        assert_eq!(
+            "[day01]\npart1 = 142\npart2 = 281\n\n[day05]\npart1 = 35\n\n[day17]\npart2 = \"LJKFH\"\n",
+            toml
+        );
+        assert_eq!(Ok(get_test_store()), AnswerStore::parse(&toml));
+    }
+
+    #[test]
+    fn test_text_round_trip() {
+        let mut store = AnswerStore::default();
+        for (part, text) in [(1, "say \"hi\"\\n\tto\r\u{8}\u{c}\u{1}"), (2, "é ✓ \u{7f}")] {
+            store.insert(6, part, Answer::from(text));
+        }
+        let toml = store.to_toml();
+        assert_eq!(
+            "[day06]\npart1 = \"say \\\"hi\\\"\\\\n\\tto\\r\\b\\f\\u0001\"\npart2 = \"é ✓ \\u007F\"\n",
+            toml
+        );
+        assert_eq!(Ok(store), AnswerStore::parse(&toml));
+
+        // text that looks like a number stays text
+        assert_eq!(r#""123""#, format_value(&Answer::from("123")));
+        assert_eq!(Ok(Answer::from("123")), parse_value(r#""123""#));
+        assert_eq!(Ok(Answer::from(123)), parse_value("123"));
+        assert_eq!(
+            Ok(Answer::from("é\u{1f384}")),
+            parse_value(r#""\u00e9\U0001F384""#)
+        );
+        assert_eq!(
+            Err(String::from("line 2: \\x is not an escape")),
+            AnswerStore::parse("[day01]\npart1 = \"\\x\"")
+        );
+        assert_eq!(
+            Err(String::from("\\u00 is not a character")),
+            parse_value(r#""\u00""#)
+        );
+        assert_eq!(
+            Err(String::from("the text ends in a \\")),
+            parse_value(r#""a\""#)
+        );
+    }
+
+    #[test]
+    fn test_load_missing_file() {
+        let path = PathBuf::from("no/such/dir/answers.toml");
+        assert_eq!(Ok(AnswerStore::default()), AnswerStore::load(&path));
+    }
+}
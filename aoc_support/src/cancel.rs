@@ -0,0 +1,122 @@
+use std::{
+    cell::RefCell,
+    panic,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+/// A flag for asking a running solver to give up, e.g. because it has run
+/// out of time. Clones share the flag.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+thread_local! {
+    /// The token of the solver running on this thread
+    static CURRENT: RefCell<Option<CancellationToken>> = const { RefCell::new(None) };
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ask the solver holding this token to stop
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Run `f` with this as the current thread's token, which `is_cancelled`
+    /// and `check` look at
+    pub fn run<R>(&self, f: impl FnOnce() -> R) -> R {
+        /// Puts the previous token back, even if `f` unwinds
+        struct Restore(Option<CancellationToken>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                CURRENT.set(self.0.take());
+            }
+        }
+
+        let _restore = Restore(CURRENT.replace(Some(self.clone())));
+        f()
+    }
+}
+
+/// The current thread's token, to hand on to any threads a solver starts
+pub fn current() -> Option<CancellationToken> {
+    CURRENT.with_borrow(|token| token.clone())
+}
+
+/// Whether the solver running on this thread has been asked to stop. This is
+/// never true unless the solver is being run with a timeout.
+pub fn is_cancelled() -> bool {
+    CURRENT.with_borrow(|token| token.as_ref().is_some_and(|t| t.is_cancelled()))
+}
+
+/// Stop the solver running on this thread if it has been asked to, by
+/// unwinding with `Cancelled`. This doesn't go through the panic hook, so
+/// nothing is printed. Solvers call it in their hot loops.
+pub fn check() {
+    if is_cancelled() {
+        panic::resume_unwind(Box::new(Cancelled));
+    }
+}
+
+/// What `check` unwinds with when the solver has been cancelled
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cancelled;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn test_cancellation_token() {
+        assert!(!is_cancelled());
+        assert!(current().is_none());
+
+        let token = CancellationToken::new();
+        let clone = token.clone();
+        token.run(|| {
+            assert!(!is_cancelled());
+            check();
+            clone.cancel();
+            assert!(is_cancelled());
+            assert!(current().is_some_and(|t| t.is_cancelled()));
+        });
+        assert!(token.is_cancelled());
+        // the token only applies while it is running
+        assert!(!is_cancelled());
+    }
+
+    #[test]
+    fn test_check_unwinds_with_cancelled() {
+        let token = CancellationToken::new();
+        token.cancel();
+        let worker = token.clone();
+        let result = thread::spawn(move || {
+            let unwound = panic::catch_unwind(|| {
+                worker.run(|| loop {
+                    check();
+                })
+            });
+            (
+                unwound.unwrap_err().downcast_ref() == Some(&Cancelled),
+                is_cancelled(),
+            )
+        })
+        .join()
+        .unwrap();
+        // unwinding puts the thread's previous token (none) back
+        assert_eq!((true, false), result);
+    }
+}
@@ -0,0 +1,414 @@
+use std::str::FromStr;
+
+use crate::parse::{self, ParseError};
+
+/// Text being parsed: the whole of it, so that errors can give their line and
+/// column, and the part of it that is still to be parsed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Input<'a> {
+    text: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Input<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self { text, rest: text }
+    }
+
+    /// The text that is still to be parsed
+    pub fn rest(&self) -> &'a str {
+        self.rest
+    }
+
+    /// An error for `found`, which must be a slice of the text
+    pub fn error(&self, found: &'a str, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.text, found, expected)
+    }
+
+    /// The rest of the current line, without its line ending
+    pub fn line(&self) -> &'a str {
+        self.rest.split('\n').next().unwrap_or_default()
+    }
+
+    /// The word at the start of the rest of the text: the letters, digits and
+    /// signs there, or the first character if it isn't one of those. This is
+    /// what an error reports as found.
+    pub fn token(&self) -> &'a str {
+        let len = match self
+            .rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '-' || c == '+'))
+        {
+            Some(0) => self.rest.chars().next().map_or(0, char::len_utf8),
+            Some(len) => len,
+            None => self.rest.len(),
+        };
+        &self.rest[..len]
+    }
+
+    fn at_line_end(&self) -> bool {
+        self.rest.is_empty() || self.rest.starts_with('\n')
+    }
+
+    fn advance(self, len: usize) -> Self {
+        Self {
+            rest: &self.rest[len..],
+            ..self
+        }
+    }
+
+    /// The same text with `rest`, a slice of it, still to be parsed
+    fn with_rest(self, rest: &'a str) -> Self {
+        Self { rest, ..self }
+    }
+}
+
+/// What a parser parsed, and the input after it
+pub type ParseResult<'a, T> = Result<(T, Input<'a>), ParseError>;
+
+/// A parser is any function from the input to a result. Functions of this
+/// shape, as well as the parsers made by the functions here, can be combined.
+pub trait Parser<'a, T>: Fn(Input<'a>) -> ParseResult<'a, T> {}
+
+impl<'a, T, F: Fn(Input<'a>) -> ParseResult<'a, T>> Parser<'a, T> for F {}
+
+/// Parse all of `text` with `parser`
+pub fn parse_all<'a, T>(parser: impl Parser<'a, T>, text: &'a str) -> Result<T, ParseError> {
+    complete(&parser, Input::new(text), "the end of the input")
+}
+
+/// Run `parser` over all of `input`, failing with `expected` at whatever it
+/// leaves
+fn complete<'a, T>(
+    parser: &impl Parser<'a, T>,
+    input: Input<'a>,
+    expected: &str,
+) -> Result<T, ParseError> {
+    let (value, rest) = parser(input)?;
+    if rest.rest.is_empty() {
+        Ok(value)
+    } else {
+        Err(rest.error(rest.line().trim_start(), expected))
+    }
+}
+
+/// Exactly the text `tag`
+pub fn tag<'a>(tag: &'static str) -> impl Parser<'a, &'a str> {
+    move |input: Input<'a>| {
+        if input.rest.starts_with(tag) {
+            Ok((&input.rest[..tag.len()], input.advance(tag.len())))
+        } else {
+            Err(input.error(input.line(), format!("{:?}", tag)))
+        }
+    }
+}
+
+/// One or more characters for which `predicate` is true, described by
+/// `expected` in an error
+pub fn take_while1<'a>(
+    predicate: impl Fn(char) -> bool,
+    expected: &'static str,
+) -> impl Parser<'a, &'a str> {
+    move |input: Input<'a>| {
+        let len = input
+            .rest
+            .find(|c: char| !predicate(c))
+            .unwrap_or(input.rest.len());
+        if len == 0 {
+            Err(input.error(input.token(), expected))
+        } else {
+            Ok((&input.rest[..len], input.advance(len)))
+        }
+    }
+}
+
+/// Any spaces or tabs, which may be none
+pub fn space0(input: Input<'_>) -> ParseResult<'_, &str> {
+    let len = input
+        .rest
+        .find(|c| c != ' ' && c != '\t')
+        .unwrap_or(input.rest.len());
+    Ok((&input.rest[..len], input.advance(len)))
+}
+
+/// At least one space or tab
+pub fn space1(input: Input<'_>) -> ParseResult<'_, &str> {
+    take_while1(|c| c == ' ' || c == '\t', "a space")(input)
+}
+
+/// A number, which is the whole of the word at the start of the input
+pub fn number<T: FromStr>(input: Input<'_>) -> ParseResult<'_, T> {
+    let token = input.token();
+    let value = token.parse().map_err(|_| input.error(token, "a number"))?;
+    Ok((value, input.advance(token.len())))
+}
+
+/// Numbers separated by spaces up to the end of the line, which may be none
+pub fn numbers<T: FromStr>(mut input: Input<'_>) -> ParseResult<'_, Vec<T>> {
+    let mut values = vec![];
+    loop {
+        (_, input) = space0(input)?;
+        if input.at_line_end() {
+            return Ok((values, input));
+        }
+        let value;
+        (value, input) = number(input)?;
+        values.push(value);
+    }
+}
+
+/// A header such as `Game 12:`, made of `label`, spaces, what `value` parses
+/// and a colon. Gives what `value` parsed.
+pub fn labelled<'a, T>(label: &'static str, value: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    move |input| {
+        let (_, input) = tag(label)(input)?;
+        let (_, input) = space1(input)?;
+        let (value, input) = value(input)?;
+        let (_, input) = tag(":")(input)?;
+        Ok((value, input))
+    }
+}
+
+/// A header such as `seeds:`, made of `label` and a colon
+pub fn header<'a>(label: &'static str) -> impl Parser<'a, ()> {
+    move |input: Input<'a>| match input.rest.strip_prefix(label) {
+        Some(rest) if rest.starts_with(':') => Ok(((), input.advance(label.len() + 1))),
+        _ => Err(input.error(input.line(), format!("{:?}", format!("{}:", label)))),
+    }
+}
+
+/// What `parser` parses, once `first` has been parsed
+pub fn preceded<'a, A, T>(
+    first: impl Parser<'a, A>,
+    parser: impl Parser<'a, T>,
+) -> impl Parser<'a, T> {
+    move |input| {
+        let (_, input) = first(input)?;
+        parser(input)
+    }
+}
+
+/// What `first` parses, and then what `second` parses
+pub fn pair<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    move |input| {
+        let (a, input) = first(input)?;
+        let (b, input) = second(input)?;
+        Ok(((a, b), input))
+    }
+}
+
+/// What `parser` parses, changed by `f`
+pub fn map<'a, T, U>(parser: impl Parser<'a, T>, f: impl Fn(T) -> U) -> impl Parser<'a, U> {
+    move |input| {
+        let (value, input) = parser(input)?;
+        Ok((f(value), input))
+    }
+}
+
+/// One or more of what `item` parses, with `delimiter` between each of them
+pub fn separated<'a, T>(
+    delimiter: &'static str,
+    item: impl Parser<'a, T>,
+) -> impl Parser<'a, Vec<T>> {
+    move |input| {
+        let (first, mut input) = item(input)?;
+        let mut items = vec![first];
+        while input.rest.starts_with(delimiter) {
+            let next;
+            (next, input) = item(input.advance(delimiter.len()))?;
+            items.push(next);
+        }
+        Ok((items, input))
+    }
+}
+
+/// What `parser` parses from the text before the next `delimiter` on the
+/// line, which it must use all of. The delimiter is skipped.
+pub fn before<'a, T>(delimiter: &'static str, parser: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    move |input: Input<'a>| {
+        let line = input.line();
+        let Some(index) = line.find(delimiter) else {
+            return Err(input.error(line, format!("{:?}", delimiter)));
+        };
+        let value = complete(
+            &parser,
+            input.with_rest(&line[..index]),
+            &format!("{:?}", delimiter),
+        )?;
+        Ok((value, input.advance(index + delimiter.len())))
+    }
+}
+
+/// What `parser` parses from the whole of the current line. The line ending
+/// is skipped.
+pub fn line<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    move |input: Input<'a>| {
+        let line = input.line();
+        let value = complete(&parser, input.with_rest(line), "the end of the line")?;
+        Ok((value, input.advance((line.len() + 1).min(input.rest.len()))))
+    }
+}
+
+/// What `parser` parses from each of the remaining lines, all of which it
+/// must use
+pub fn lines<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |input: Input<'a>| {
+        let values = input
+            .rest
+            .lines()
+            .map(|line| complete(&parser, input.with_rest(line), "the end of the line"))
+            .collect::<Result<_, _>>()?;
+        Ok((values, input.advance(input.rest.len())))
+    }
+}
+
+/// What `parser` parses from each of the remaining sections separated by
+/// blank lines, all of which it must use
+pub fn sections<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |input: Input<'a>| {
+        let values = parse::sections(input.rest)
+            .into_iter()
+            .map(|section| complete(&parser, input.with_rest(section), "the end of the section"))
+            .collect::<Result<_, _>>()?;
+        Ok((values, input.advance(input.rest.len())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_support::test_support::TestCase;
+
+    /// The error `parser` gives for `text`, as it would be printed
+    fn error<'a, T: std::fmt::Debug>(parser: impl Parser<'a, T>, text: &'a str) -> String {
+        parse_all(parser, text).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_primitives() {
+        assert_eq!(Ok("Game"), parse_all(tag("Game"), "Game"));
+        assert_eq!(Ok(-12), parse_all(number::<i32>, "-12"));
+        assert_eq!(
+            Ok("seed"),
+            parse_all(take_while1(char::is_alphabetic, "a name"), "seed")
+        );
+        assert_eq!(
+            Ok(vec![79, 14, 55]),
+            parse_all(numbers::<u32>, "  79 14\t55")
+        );
+        assert_eq!(Ok(vec![]), parse_all(numbers::<u32>, ""));
+
+        let test_cases = [
+            TestCase {
+                input: error(tag("Game"), "Card 1"),
+                expected: "line 1, column 1: expected \"Game\", found \"Card 1\"",
+            },
+            TestCase {
+                input: error(number::<u8>, "256"),
+                expected: "line 1, column 1: expected a number, found \"256\"",
+            },
+            TestCase {
+                input: error(numbers::<i64>, "41 48 x6 83"),
+                expected: "line 1, column 7: expected a number, found \"x6\"",
+            },
+            TestCase {
+                input: error(numbers::<i64>, "41, 48"),
+                expected: "line 1, column 3: expected a number, found \",\"",
+            },
+            TestCase {
+                input: error(space1, "x"),
+                expected: "line 1, column 1: expected a space, found \"x\"",
+            },
+            TestCase {
+                input: error(tag("a"), "ab"),
+                expected: "line 1, column 2: expected the end of the input, found \"b\"",
+            },
+        ];
+        for TestCase { input, expected } in test_cases {
+            assert_eq!(expected, input);
+        }
+    }
+
+    #[test]
+    fn test_headers() {
+        assert_eq!(
+            Ok(12),
+            parse_all(labelled("Card", number::<u32>), "Card  12:")
+        );
+        assert_eq!(
+            Ok(vec![7, 15]),
+            parse_all(preceded(header("Time"), numbers::<u32>), "Time:  7  15")
+        );
+        assert_eq!(
+            "line 1, column 6: expected a number, found \"one\"",
+            error(labelled("Card", number::<u32>), "Card one:")
+        );
+        assert_eq!(
+            "line 1, column 1: expected \"Time:\", found \"Times: 7\"",
+            error(header("Time"), "Times: 7")
+        );
+    }
+
+    #[test]
+    fn test_sequences() {
+        let card = pair(before(" | ", numbers::<u32>), numbers::<u32>);
+        assert_eq!(
+            Ok((vec![41, 48], vec![83, 86, 6])),
+            parse_all(&card, "41 48 | 83 86  6")
+        );
+        assert_eq!(
+            "line 1, column 1: expected \" | \", found \"41 48 83\"",
+            error(&card, "41 48 83")
+        );
+
+        let cube = pair(
+            number::<u32>,
+            preceded(tag(" "), take_while1(char::is_alphabetic, "a colour")),
+        );
+        let hands = separated("; ", separated(", ", &cube));
+        assert_eq!(
+            Ok(vec![vec![(3, "blue"), (4, "red")], vec![(2, "green")]]),
+            parse_all(&hands, "3 blue, 4 red; 2 green")
+        );
+        assert_eq!(
+            "line 1, column 17: expected \" \", found \"_green\"",
+            error(&hands, "3 blue, 4 red; 2_green")
+        );
+        assert_eq!(
+            Ok(5),
+            parse_all(map(numbers::<u32>, |n| n.len()), "1 2 3 4 5")
+        );
+    }
+
+    #[test]
+    fn test_lines_and_sections() {
+        const TEXT: &str = "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50 48\n\nx map:\n1 2 3";
+        let seeds = line(preceded(header("seeds"), numbers::<i64>));
+        let entry = pair(
+            line(before(" map:", tag("seed-to-soil"))),
+            lines(numbers::<i64>),
+        );
+        let almanac = pair(&seeds, sections(&entry));
+        assert_eq!(
+            "line 7, column 1: expected \"seed-to-soil\", found \"x\"",
+            error(&almanac, TEXT)
+        );
+        assert_eq!(
+            Ok((
+                vec![79, 14],
+                vec![("seed-to-soil", vec![vec![50, 98, 2], vec![52, 50, 48]])]
+            )),
+            parse_all(&almanac, &TEXT[..48])
+        );
+        assert_eq!(
+            "line 2, column 1: expected the end of the input, found \"Time: 1\"",
+            error(&seeds, "seeds: 79\nTime: 1")
+        );
+        assert_eq!(
+            "line 4, column 4: expected the end of the line, found \"map:\"",
+            error(lines(tag("50 ")), "50 \n50 \n50 \n50 map:")
+        );
+    }
+}
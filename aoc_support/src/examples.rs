@@ -0,0 +1,164 @@
+use crate::{
+    answer::Answer,
+    answers::{format_value, parse_value},
+};
+use std::{fs, path::Path};
+
+/// A puzzle's example input and the answers the puzzle gives for it, as
+/// saved by `cargo aoc examples` in a day's `examples` directory:
+/// `example1.txt` holds the input and `example1.answers` the answers,
+/// one `partN = answer` line per part, written the way `answers.toml`
+/// writes them.
+#[derive(Debug, PartialEq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+impl Example {
+    /// The expected answer to `part`, if the puzzle gave one for this example
+    pub fn expected(&self, part: u32) -> Option<&Answer> {
+        match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// Load every example saved in the `examples` directory of the crate at
+/// `crate_dir` (pass `env!("CARGO_MANIFEST_DIR")`), ordered by name. Panics
+/// if they can't be read, since they are only loaded by tests.
+pub fn load_examples(crate_dir: &str) -> Vec<Example> {
+    let examples_dir = Path::new(crate_dir).join("examples");
+    let entries = fs::read_dir(&examples_dir)
+        .unwrap_or_else(|e| panic!("could not read {}: {}", examples_dir.display(), e));
+
+    let mut names: Vec<String> = entries
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|e| e == "txt"))
+        .map(|path| path.file_stem().unwrap().to_string_lossy().into_owned())
+        .collect();
+    names.sort();
+    names
+        .iter()
+        .map(|name| load_example(crate_dir, name))
+        .collect()
+}
+
+/// Load the example called `name` (e.g. "example1") from the `examples`
+/// directory of the crate at `crate_dir`
+pub fn load_example(crate_dir: &str, name: &str) -> Example {
+    let examples_dir = Path::new(crate_dir).join("examples");
+    let input_path = examples_dir.join(format!("{}.txt", name));
+    let input = fs::read_to_string(&input_path)
+        .unwrap_or_else(|e| panic!("could not read {}: {}", input_path.display(), e));
+
+    // an example doesn't need answers, e.g. one that only illustrates a step
+    let answers_path = examples_dir.join(format!("{}.answers", name));
+    let answers = fs::read_to_string(&answers_path).unwrap_or_default();
+    let (part1, part2) =
+        parse_answers(&answers).unwrap_or_else(|e| panic!("{}: {}", answers_path.display(), e));
+
+    Example {
+        name: String::from(name),
+        input,
+        part1,
+        part2,
+    }
+}
+
+/// Parse the `part1 = 142` lines of an answers file
+pub fn parse_answers(text: &str) -> Result<(Option<Answer>, Option<Answer>), String> {
+    let mut answers = (None, None);
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (key, value) = line
+            .split_once('=')
+            .ok_or(format!("line {}: expected partN = answer", index + 1))?;
+        let value =
+            Some(parse_value(value.trim()).map_err(|e| format!("line {}: {}", index + 1, e))?);
+        match key.trim() {
+            "part1" => answers.0 = value,
+            "part2" => answers.1 = value,
+            key => return Err(format!("line {}: {} is not a part", index + 1, key)),
+        }
+    }
+    Ok(answers)
+}
+
+/// Format answers for an answers file, the reverse of `parse_answers`
+pub fn format_answers(part1: Option<&Answer>, part2: Option<&Answer>) -> String {
+    [("part1", part1), ("part2", part2)]
+        .iter()
+        .filter_map(|(key, answer)| {
+            answer.map(|answer| format!("{} = {}\n", key, format_value(answer)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        assert_eq!(
+            Ok((Some(Answer::from(142)), Some(Answer::from(281)))),
+            parse_answers("part1 = 142\npart2 = 281\n")
+        );
+        assert_eq!(
+            Ok((None, Some(Answer::from("ABC")))),
+            parse_answers("# only part 2\npart2 = \"ABC\"\n")
+        );
+        assert_eq!(
+            Ok((Some(Answer::from("say \"hi\"")), None)),
+            parse_answers(r#"part1 = "say \"hi\"""#)
+        );
+        assert_eq!(Ok((None, None)), parse_answers(""));
+        assert_eq!(
+            Err(String::from("line 1: part3 is not a part")),
+            parse_answers("part3 = 1")
+        );
+        assert_eq!(
+            Err(String::from("line 2: expected partN = answer")),
+            parse_answers("part1 = 1\n2")
+        );
+        assert_eq!(
+            Err(String::from("line 1: \\x is not an escape")),
+            parse_answers(r#"part1 = "\x""#)
+        );
+    }
+
+    #[test]
+    fn test_format_answers() {
+        assert_eq!(
+            "part1 = 142\npart2 = 281\n",
+            format_answers(Some(&Answer::from(142)), Some(&Answer::from(281)))
+        );
+        assert_eq!(
+            "part2 = 281\n",
+            format_answers(None, Some(&Answer::from(281)))
+        );
+        assert_eq!(
+            Ok((Some(Answer::from(4361)), None)),
+            parse_answers(&format_answers(Some(&Answer::from(4361)), None))
+        );
+
+        // text that looks like a number stays text
+        let text = Answer::from("123\t\"4\"");
+        assert_eq!(
+            "part1 = \"123\\t\\\"4\\\"\"\n",
+            format_answers(Some(&text), None)
+        );
+        assert_eq!(
+            Ok((Some(text.clone()), None)),
+            parse_answers(&format_answers(Some(&text), None))
+        );
+    }
+}
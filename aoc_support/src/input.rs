@@ -0,0 +1,254 @@
+use std::{
+    env, fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    process,
+};
+
+/// Environment variable naming a directory of dayNN.txt puzzle inputs
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+/// The directory puzzle inputs are read from when nothing else is specified,
+/// which is `inputs` at the root of the workspace
+pub fn default_input_dir() -> PathBuf {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    manifest_dir.parent().unwrap_or(manifest_dir).join("inputs")
+}
+
+/// Where a day's puzzle input comes from
+#[derive(Debug, PartialEq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    Embedded(&'static str),
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::Embedded(_) => write!(f, "the embedded input"),
+        }
+    }
+}
+
+impl InputSource {
+    /// Work out where to read a day's puzzle input from. In order of
+    /// preference, this is:
+    ///
+    /// - the path following `--input` in `args` (`-` means stdin)
+    /// - `dayNN.txt` in the `input_dir` taken from `AOC_INPUT_DIR`
+    /// - the `embedded` input kept in the day's source, if it has one
+    /// - `dayNN.txt` in the workspace's `inputs` directory
+    pub fn new(
+        day: &str,
+        args: &[String],
+        input_dir: Option<&str>,
+        embedded: Option<&'static str>,
+    ) -> Self {
+        let input_arg = args.iter().enumerate().find_map(|(index, arg)| {
+            if arg == "--input" {
+                args.get(index + 1).map(|a| a.as_str())
+            } else {
+                arg.strip_prefix("--input=")
+            }
+        });
+
+        match input_arg {
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(PathBuf::from(path)),
+            None => match (input_dir, embedded) {
+                (None, Some(embedded)) => InputSource::Embedded(embedded),
+                _ => {
+                    let input_dir = input_dir.map_or_else(default_input_dir, PathBuf::from);
+                    InputSource::File(input_dir.join(format!("day{}.txt", day)))
+                }
+            },
+        }
+    }
+
+    /// Read the input, normalized as by `normalize`
+    pub fn read(&self) -> Result<String, InputError> {
+        let result = match self {
+            InputSource::File(path) => fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+            InputSource::Embedded(input) => Ok(String::from(*input)),
+        };
+
+        result
+            .map(|input| normalize(&input))
+            .map_err(|error| InputError {
+                source: self.to_string(),
+                error,
+            })
+    }
+}
+
+/// Tidy up a puzzle input so that the parsers don't have to: drop a byte
+/// order mark, turn CRLF line endings into LF, and remove trailing
+/// whitespace from each line and from the end of the input. An input that
+/// isn't empty ends with a single newline.
+pub fn normalize(input: &str) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut normalized = String::with_capacity(input.len());
+    for line in input.lines() {
+        normalized.push_str(line.trim_end());
+        normalized.push('\n');
+    }
+
+    normalized.truncate(normalized.trim_end().len());
+    if !normalized.is_empty() {
+        normalized.push('\n');
+    }
+    normalized
+}
+
+/// A puzzle input that couldn't be read
+#[derive(Debug)]
+pub struct InputError {
+    source: String,
+    error: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "could not read puzzle input from {}: {}",
+            self.source, self.error
+        )?;
+        if self.error.kind() == io::ErrorKind::NotFound {
+            write!(
+                f,
+                "\nsave the puzzle input there, set {} to the directory holding it, \
+                or pass --input <path> (--input - reads stdin)",
+                INPUT_DIR_ENV
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Read the puzzle input for `day` (e.g., "01") from the source selected by
+/// the command line arguments and environment.
+pub fn load(day: &str, embedded: Option<&'static str>) -> Result<String, InputError> {
+    let args: Vec<_> = env::args().skip(1).collect();
+    let input_dir = env::var(INPUT_DIR_ENV).ok();
+    InputSource::new(day, &args, input_dir.as_deref(), embedded).read()
+}
+
+/// As for `load`, but report a missing input and exit rather than returning
+/// an error. Intended for use at the top of a day's `main`.
+pub fn load_or_exit(day: &str, embedded: Option<&'static str>) -> String {
+    load(day, embedded).unwrap_or_else(|e| {
+        eprintln!("Day {}: {}", day, e);
+        process::exit(1);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_support::test_support::TestCase;
+
+    fn to_args(args: &str) -> Vec<String> {
+        args.split_ascii_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_input_source() {
+        let test_cases = [
+            TestCase {
+                input: ("", None, None),
+                expected: InputSource::File(default_input_dir().join("day01.txt")),
+            },
+            TestCase {
+                input: ("", None, Some("1abc2")),
+                expected: InputSource::Embedded("1abc2"),
+            },
+            TestCase {
+                input: ("", Some("/tmp/aoc"), Some("1abc2")),
+                expected: InputSource::File(PathBuf::from("/tmp/aoc/day01.txt")),
+            },
+            TestCase {
+                input: ("--input my_input.txt", None, Some("1abc2")),
+                expected: InputSource::File(PathBuf::from("my_input.txt")),
+            },
+            TestCase {
+                input: ("", Some("/tmp/aoc"), None),
+                expected: InputSource::File(PathBuf::from("/tmp/aoc/day01.txt")),
+            },
+            TestCase {
+                input: ("--input my_input.txt", Some("/tmp/aoc"), None),
+                expected: InputSource::File(PathBuf::from("my_input.txt")),
+            },
+            TestCase {
+                input: ("--input=my_input.txt", None, None),
+                expected: InputSource::File(PathBuf::from("my_input.txt")),
+            },
+            TestCase {
+                input: ("--input -", Some("/tmp/aoc"), None),
+                expected: InputSource::Stdin,
+            },
+        ];
+        for TestCase { input, expected } in test_cases.iter() {
+            let (args, input_dir, embedded) = *input;
+            assert_eq!(
+                InputSource::new("01", &to_args(args), input_dir, embedded),
+                *expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_normalize() {
+        let test_cases = [
+            TestCase {
+                input: "1abc2\npqr3stu8vwx\n",
+                expected: "1abc2\npqr3stu8vwx\n",
+            },
+            TestCase {
+                input: "\u{feff}1abc2\r\npqr3stu8vwx\r\n",
+                expected: "1abc2\npqr3stu8vwx\n",
+            },
+            TestCase {
+                input: "seeds: 79 14  \n\t\nseed-to-soil map:\t\n50 98 2",
+                expected: "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n",
+            },
+            TestCase {
+                input: "  .#.\n  #..\n\n\n  \n",
+                expected: "  .#.\n  #..\n",
+            },
+            TestCase {
+                input: " \r\n\n",
+                expected: "",
+            },
+        ];
+        for TestCase { input, expected } in test_cases {
+            assert_eq!(expected, normalize(input));
+        }
+    }
+
+    #[test]
+    fn test_read_missing_input() {
+        let source = InputSource::File(PathBuf::from("no/such/dir/day01.txt"));
+        let error = source.read().unwrap_err();
+        let message = error.to_string();
+        assert!(message.starts_with("could not read puzzle input from no/such/dir/day01.txt"));
+        assert!(message.contains(INPUT_DIR_ENV));
+    }
+
+    #[test]
+    fn test_read_input_file() {
+        let path = env::temp_dir().join("aoc_support_test_read_input_file.txt");
+        fs::write(&path, "1abc2\r\n").unwrap();
+        assert_eq!("1abc2\n", InputSource::File(path.clone()).read().unwrap());
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,10 @@
+pub mod answer;
+pub mod answers;
+pub mod cancel;
+pub mod combinators;
+pub mod examples;
+pub mod input;
+pub mod parse;
+pub mod report;
+pub mod rng;
+pub mod solution;
@@ -0,0 +1,212 @@
+use std::{fmt, str::FromStr};
+
+/// Where a puzzle input failed to parse, and why
+#[derive(Debug, PartialEq, Clone)]
+pub struct ParseError {
+    /// The line of the offending text, counting from 1
+    pub line: usize,
+    /// The column of the offending text in characters, counting from 1
+    pub column: usize,
+    /// The offending text, which is empty if the text ended too soon
+    pub found: String,
+    /// What should have been there instead
+    pub expected: String,
+}
+
+impl ParseError {
+    /// An error for `found`, which must be a slice of `text`, the string
+    /// being parsed. The line and column are those of `found` in `text`.
+    pub fn new(text: &str, found: &str, expected: impl Into<String>) -> Self {
+        let (line, column) = position(text, found);
+        Self {
+            line,
+            column,
+            found: String::from(found),
+            expected: expected.into(),
+        }
+    }
+
+    /// An error for `text` ending before `expected` was found
+    pub fn end_of(text: &str, expected: impl Into<String>) -> Self {
+        Self::new(text, &text[text.len()..], expected)
+    }
+
+    /// Move an error from parsing `inner`, a slice of `outer`, so that its
+    /// line and column are positions in `outer`
+    pub fn within(self, outer: &str, inner: &str) -> Self {
+        let (line, column) = position(outer, inner);
+        Self {
+            line: self.line + line - 1,
+            column: if self.line == 1 {
+                self.column + column - 1
+            } else {
+                self.column
+            },
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, ",
+            self.line, self.column, self.expected
+        )?;
+        if self.found.is_empty() {
+            write!(f, "found nothing")
+        } else {
+            write!(f, "found {:?}", self.found)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The line and column, counting from 1, at which `inner` starts in `outer`.
+/// `inner` must be a slice of `outer`; if it isn't, this is the start of
+/// `outer`.
+pub fn position(outer: &str, inner: &str) -> (usize, usize) {
+    let offset = (inner.as_ptr() as usize).wrapping_sub(outer.as_ptr() as usize);
+    let offset = if outer.is_char_boundary(offset) {
+        offset
+    } else {
+        0
+    };
+
+    let before = &outer[..offset];
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+/// Parse `token`, a slice of `text`, as a number
+pub fn parse_number<T: FromStr>(text: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::new(text, token, "a number"))
+}
+
+/// Split `part`, a slice of `text`, around the first `delimiter` in it
+pub fn split_once<'a>(
+    text: &str,
+    part: &'a str,
+    delimiter: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    part.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(text, part, format!("{:?}", delimiter)))
+}
+
+/// Split `text` into the sections that are separated by blank lines. Each
+/// section is a slice of `text` without the line ending after it.
+pub fn sections(text: &str) -> Vec<&str> {
+    let mut sections = vec![];
+    let mut section_start = None;
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        match (line.trim().is_empty(), section_start) {
+            (true, Some(start)) => {
+                sections.push(text[start..offset].trim_end_matches(['\r', '\n']));
+                section_start = None;
+            }
+            (false, None) => section_start = Some(offset),
+            _ => (),
+        }
+        offset += line.len();
+    }
+    if let Some(start) = section_start {
+        sections.push(text[start..].trim_end_matches(['\r', '\n']));
+    }
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_support::test_support::TestCase;
+
+    const TEXT: &str = "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n";
+
+    #[test]
+    fn test_position() {
+        let test_cases = [
+            TestCase {
+                input: &TEXT[0..5],
+                expected: (1, 1),
+            },
+            TestCase {
+                input: &TEXT[7..9],
+                expected: (1, 8),
+            },
+            TestCase {
+                input: &TEXT[14..18],
+                expected: (3, 1),
+            },
+            TestCase {
+                input: &TEXT[35..],
+                expected: (4, 4),
+            },
+        ];
+        for TestCase { input, expected } in test_cases.iter() {
+            assert_eq!(position(TEXT, input), *expected);
+        }
+        assert_eq!((1, 1), position(TEXT, &String::from("not in the text")));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let line = &TEXT[14..31];
+        let error = ParseError::new(line, &line[8..], "\"map\"");
+        assert_eq!((1, 9), (error.line, error.column));
+        let error = error.within(TEXT, line);
+        assert_eq!((3, 9), (error.line, error.column));
+        assert_eq!(
+            "line 3, column 9: expected \"map\", found \"soil map:\"",
+            error.to_string()
+        );
+
+        let error = ParseError::end_of("Game 1", "\": \"");
+        assert_eq!(
+            "line 1, column 7: expected \": \", found nothing",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_parse_number() {
+        assert_eq!(Ok(79), parse_number::<i64>(TEXT, &TEXT[7..9]));
+        assert_eq!(
+            Err(ParseError {
+                line: 1,
+                column: 1,
+                found: String::from("seeds"),
+                expected: String::from("a number"),
+            }),
+            parse_number::<i64>(TEXT, &TEXT[0..5])
+        );
+    }
+
+    #[test]
+    fn test_split_once() {
+        assert_eq!(Ok(("seeds", "79 14")), split_once(TEXT, &TEXT[..12], ": "));
+        assert_eq!(
+            "line 4, column 1: expected \": \", found \"50 98 2\"",
+            split_once(TEXT, &TEXT[32..39], ": ")
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_sections() {
+        assert_eq!(
+            vec!["seeds: 79 14", "seed-to-soil map:\n50 98 2"],
+            sections(TEXT)
+        );
+        assert_eq!(vec!["  a", "b\r\nc"], sections("\n  a\r\n  \r\nb\r\nc"));
+        assert!(sections("").is_empty());
+    }
+}
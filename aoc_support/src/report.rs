@@ -0,0 +1,375 @@
+use crate::{
+    answer::Answer,
+    solution::{format_day, SolveError, Solver},
+};
+use std::{
+    fmt::Write as _,
+    io::{self, Write as _},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+/// How the answers are printed
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum Format {
+    /// `Day NN Part P: answer` lines
+    #[default]
+    Text,
+    /// A JSON array holding an object per day and part, printed once every
+    /// day has been run
+    Json,
+    /// An object per day and part on its own line, printed as soon as the
+    /// day has been run so that long runs can be tailed
+    JsonLines,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "jsonl" => Ok(Format::JsonLines),
+            _ => Err(format!(
+                "{} is not an output format (text, json or jsonl)",
+                s
+            )),
+        }
+    }
+}
+
+impl Format {
+    /// Read the format from `--format <format>` or `--format=<format>` in
+    /// `args`, defaulting to text
+    pub fn from_args(args: &[String]) -> Result<Self, String> {
+        let format_arg = args.iter().enumerate().find_map(|(index, arg)| {
+            if arg == "--format" {
+                Some(args.get(index + 1).map_or("", |a| a.as_str()))
+            } else {
+                arg.strip_prefix("--format=")
+            }
+        });
+        format_arg.map_or(Ok(Format::default()), str::parse)
+    }
+}
+
+/// The answer to one part, or why it wasn't solved (e.g. it timed out or
+/// the solution couldn't solve it), and how long it took
+#[derive(Debug, PartialEq)]
+pub struct PartReport {
+    pub part: u32,
+    pub answer: Result<Answer, String>,
+    pub elapsed: Duration,
+}
+
+/// The outcome of running a day: the answers to both parts, or why the day
+/// couldn't be solved
+#[derive(Debug, PartialEq)]
+pub struct DayReport {
+    pub day: u32,
+    pub result: Result<DayAnswers, String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DayAnswers {
+    /// How long the input took to parse
+    pub parse: Duration,
+    pub parts: Vec<PartReport>,
+}
+
+impl DayReport {
+    /// Parse `input` and solve both parts, timing each step. An input that
+    /// can't be parsed fails the day.
+    pub fn solve(solver: &dyn Solver, input: &str) -> Self {
+        let start = Instant::now();
+        let parsed_input = match solver.parse(input) {
+            Ok(parsed_input) => parsed_input,
+            Err(e) => {
+                return Self::failed(
+                    solver.day(),
+                    format!("could not parse the puzzle input: {}", e),
+                )
+            }
+        };
+        let parse = start.elapsed();
+
+        let solve_part = |part, solve: &dyn Fn() -> Result<Answer, SolveError>| {
+            let start = Instant::now();
+            let answer = solve();
+            PartReport {
+                part,
+                answer: answer.map_err(|e| e.to_string()),
+                elapsed: start.elapsed(),
+            }
+        };
+        let parts = vec![
+            solve_part(1, &|| solver.part1(parsed_input.as_ref())),
+            solve_part(2, &|| solver.part2(parsed_input.as_ref())),
+        ];
+
+        Self {
+            day: solver.day(),
+            result: Ok(DayAnswers { parse, parts }),
+        }
+    }
+
+    /// A day that couldn't be solved, e.g. because its input is missing
+    pub fn failed(day: u32, error: String) -> Self {
+        Self {
+            day,
+            result: Err(error),
+        }
+    }
+
+    /// Whether both parts were solved
+    pub fn is_ok(&self) -> bool {
+        self.result
+            .as_ref()
+            .is_ok_and(|answers| answers.parts.iter().all(|part| part.answer.is_ok()))
+    }
+
+    /// An object per part, giving the answer as a string (so that answers
+    /// wider than 53 bits survive being read as JavaScript numbers) and
+    /// the times in nanoseconds. A part that wasn't solved, or every part of
+    /// a day that failed, has the error and no answer.
+    pub fn to_json(&self) -> Vec<String> {
+        match &self.result {
+            Ok(answers) => answers
+                .parts
+                .iter()
+                .map(|part| {
+                    let (answer, error) = match &part.answer {
+                        Ok(answer) => (json_string(&answer.to_string()), String::from("null")),
+                        Err(error) => (String::from("null"), json_string(error)),
+                    };
+                    format!(
+                        r#"{{"day":{},"part":{},"answer":{},"parse_ns":{},"elapsed_ns":{},"error":{}}}"#,
+                        self.day,
+                        part.part,
+                        answer,
+                        answers.parse.as_nanos(),
+                        part.elapsed.as_nanos(),
+                        error
+                    )
+                })
+                .collect(),
+            Err(error) => (1..=2)
+                .map(|part| {
+                    format!(
+                        r#"{{"day":{},"part":{},"answer":null,"parse_ns":null,"elapsed_ns":null,"error":{}}}"#,
+                        self.day,
+                        part,
+                        json_string(error)
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Quote and escape `s` as a JSON string
+pub fn json_string(s: &str) -> String {
+    let mut json = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// Prints day reports in the chosen format
+pub struct Printer {
+    format: Format,
+    /// JSON objects held back until `finish` when printing a JSON array
+    objects: Vec<String>,
+}
+
+impl Printer {
+    pub fn new(format: Format) -> Self {
+        Self {
+            format,
+            objects: vec![],
+        }
+    }
+
+    pub fn print(&mut self, report: &DayReport) {
+        match self.format {
+            Format::Text => {
+                let day = format_day(report.day);
+                match &report.result {
+                    Ok(answers) => {
+                        for part in answers.parts.iter() {
+                            match &part.answer {
+                                Ok(answer) => {
+                                    println!("Day {} Part {}: {}", day, part.part, answer)
+                                }
+                                Err(error) => {
+                                    eprintln!("Day {} Part {}: {}", day, part.part, error)
+                                }
+                            }
+                        }
+                    }
+                    Err(error) => eprintln!("Day {}: {}", day, error),
+                }
+            }
+            Format::Json | Format::JsonLines => self.print_json(report.to_json()),
+        }
+    }
+
+    /// Print JSON objects, such as those for a day's report or timings: held
+    /// back until `finish` for a JSON array, and otherwise each on its own
+    /// line straight away
+    pub fn print_json(&mut self, objects: Vec<String>) {
+        if self.format == Format::Json {
+            self.objects.extend(objects);
+        } else {
+            let mut stdout = io::stdout().lock();
+            for object in objects {
+                writeln!(stdout, "{}", object).unwrap();
+            }
+            stdout.flush().unwrap();
+        }
+    }
+
+    /// Print anything held back until every day has been reported
+    pub fn finish(self) {
+        if self.format == Format::Json {
+            print!("{}", format_json_array(&self.objects));
+        }
+    }
+}
+
+fn format_json_array(objects: &[String]) -> String {
+    if objects.is_empty() {
+        return String::from("[]\n");
+    }
+    format!("[\n  {}\n]\n", objects.join(",\n  "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_support::test_support::TestCase;
+
+    fn args(args: &str) -> Vec<String> {
+        args.split_ascii_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_format_from_args() {
+        let test_cases = [
+            TestCase {
+                input: "",
+                expected: Ok(Format::Text),
+            },
+            TestCase {
+                input: "--input my_input.txt --format json",
+                expected: Ok(Format::Json),
+            },
+            TestCase {
+                input: "--format=jsonl",
+                expected: Ok(Format::JsonLines),
+            },
+            TestCase {
+                input: "--format xml",
+                expected: Err(String::from(
+                    "xml is not an output format (text, json or jsonl)",
+                )),
+            },
+            TestCase {
+                input: "--format",
+                expected: Err(String::from(
+                    " is not an output format (text, json or jsonl)",
+                )),
+            },
+        ];
+        for TestCase { input, expected } in test_cases.iter() {
+            assert_eq!(Format::from_args(&args(input)), *expected);
+        }
+    }
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(r#""LJKFH""#, json_string("LJKFH"));
+        assert_eq!(
+            r#""a \"b\" c:\\d\nnext\u0007""#,
+            json_string("a \"b\" c:\\d\nnext\u{7}")
+        );
+    }
+
+    #[test]
+    fn test_to_json() {
+        let report = DayReport {
+            day: 6,
+            result: Ok(DayAnswers {
+                parse: Duration::from_nanos(500),
+                parts: vec![
+                    PartReport {
+                        part: 1,
+                        answer: Ok(Answer::from(288)),
+                        elapsed: Duration::from_nanos(1_200),
+                    },
+                    PartReport {
+                        part: 2,
+                        answer: Ok(Answer::from(u128::MAX)),
+                        elapsed: Duration::from_micros(3),
+                    },
+                ],
+            }),
+        };
+        assert_eq!(
+            vec![
+                r#"{"day":6,"part":1,"answer":"288","parse_ns":500,"elapsed_ns":1200,"error":null}"#,
+                r#"{"day":6,"part":2,"answer":"340282366920938463463374607431768211455","parse_ns":500,"elapsed_ns":3000,"error":null}"#,
+            ],
+            report.to_json()
+        );
+        assert!(report.is_ok());
+
+        let report = DayReport {
+            day: 5,
+            result: Ok(DayAnswers {
+                parse: Duration::from_nanos(500),
+                parts: vec![PartReport {
+                    part: 2,
+                    answer: Err(String::from("TIMEOUT after 5s")),
+                    elapsed: Duration::from_secs(5),
+                }],
+            }),
+        };
+        assert_eq!(
+            vec![
+                r#"{"day":5,"part":2,"answer":null,"parse_ns":500,"elapsed_ns":5000000000,"error":"TIMEOUT after 5s"}"#,
+            ],
+            report.to_json()
+        );
+        assert!(!report.is_ok());
+
+        let report = DayReport::failed(17, String::from("no \"input\""));
+        assert_eq!(
+            vec![
+                r#"{"day":17,"part":1,"answer":null,"parse_ns":null,"elapsed_ns":null,"error":"no \"input\""}"#,
+                r#"{"day":17,"part":2,"answer":null,"parse_ns":null,"elapsed_ns":null,"error":"no \"input\""}"#,
+            ],
+            report.to_json()
+        );
+    }
+
+    #[test]
+    fn test_format_json_array() {
+        assert_eq!("[]\n", format_json_array(&[]));
+        assert_eq!(
+            "[\n  {\"day\":1},\n  {\"day\":2}\n]\n",
+            format_json_array(&[String::from(r#"{"day":1}"#), String::from(r#"{"day":2}"#)])
+        );
+    }
+}
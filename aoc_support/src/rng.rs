@@ -0,0 +1,117 @@
+use std::{fmt::Debug, ops::RangeInclusive};
+
+/// A small, fast pseudo-random generator (splitmix64). The same seed always
+/// gives the same values, which is what makes generated inputs, and property
+/// test failures, reproducible.
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A value in `low..=high`, which must be no wider than the 64 bit types
+    fn in_range(&mut self, low: i128, high: i128) -> i128 {
+        let span = (high - low) as u128 + 1;
+        let random = (u128::from(self.next_u64()) << 64) | u128::from(self.next_u64());
+        low + (random % span) as i128
+    }
+
+    /// An index into a slice of length `len`, which must not be 0
+    pub fn index(&mut self, len: usize) -> usize {
+        self.in_range(0, len as i128 - 1) as usize
+    }
+
+    /// A value in `range`, each equally likely
+    pub fn range<T: Int>(&mut self, range: RangeInclusive<T>) -> T {
+        T::from_i128(self.in_range(range.start().to_i128(), range.end().to_i128()))
+    }
+
+    /// One of `items`, each equally likely. `items` must not be empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    /// Put `items` in a random order
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+/// The integer types `Rng::range` can pick from. An `i128` range must be no
+/// wider than the 64 bit types.
+pub trait Int: Copy + Debug {
+    fn to_i128(self) -> i128;
+    fn from_i128(value: i128) -> Self;
+}
+
+macro_rules! impl_int {
+    ($($t:ty),+) => {
+        $(
+            impl Int for $t {
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+
+                fn from_i128(value: i128) -> Self {
+                    value as $t
+                }
+            }
+        )+
+    };
+}
+
+impl_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng_is_reproducible() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        let mut c = Rng::new(43);
+        let (a, b, c): (Vec<_>, Vec<_>, Vec<_>) = (0..10)
+            .map(|_| (a.next_u64(), b.next_u64(), c.next_u64()))
+            .fold(
+                (vec![], vec![], vec![]),
+                |(mut a, mut b, mut c), (x, y, z)| {
+                    a.push(x);
+                    b.push(y);
+                    c.push(z);
+                    (a, b, c)
+                },
+            );
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_rng_helpers() {
+        let mut rng = Rng::new(2023);
+        let values: Vec<_> = (0..200).map(|_| rng.range(10..=12u32)).collect();
+        assert!(values.iter().all(|v| (10..=12).contains(v)));
+        assert!(values.contains(&10) && values.contains(&12));
+
+        assert!(["x", "y"].contains(rng.choose(&["x", "y"])));
+
+        let mut items: Vec<_> = (0..20).collect();
+        rng.shuffle(&mut items);
+        assert_ne!((0..20).collect::<Vec<_>>(), items);
+        items.sort();
+        assert_eq!((0..20).collect::<Vec<_>>(), items);
+    }
+}
@@ -0,0 +1,211 @@
+use crate::{
+    answer::Answer,
+    input,
+    parse::ParseError,
+    report::{DayReport, Format, Printer},
+};
+use std::{any::Any, env, fmt, process};
+
+/// A solution to one day's puzzle. The puzzle input is parsed once and the
+/// parsed form is handed to each part.
+pub trait Solution {
+    /// The parsed form of the puzzle input that both parts work from
+    type Input: 'static;
+
+    /// The day of the puzzle, 1 to 25
+    fn day(&self) -> u32;
+
+    /// Puzzle input that is short enough to keep in the source, used if the
+    /// input hasn't been given any other way
+    fn embedded_input(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// A made-up but valid puzzle input of roughly `size` (lines, cards,
+    /// rows, ... whatever the puzzle is made of), the same for the same
+    /// `seed`, for stress tests and benchmarks where the real input isn't
+    /// available
+    fn generate_input(&self, _seed: u64, _size: usize) -> Option<String> {
+        None
+    }
+
+    /// Parse the puzzle input, reporting where it isn't in the expected form
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Solve part 1, or say why it can't be solved for an input that parsed,
+    /// e.g. because the answer is too big to work out
+    fn part1(&self, input: &Self::Input) -> Result<Answer, SolveError>;
+
+    /// Solve part 2, or say why it can't be solved for an input that parsed
+    fn part2(&self, input: &Self::Input) -> Result<Answer, SolveError>;
+}
+
+/// Why a part couldn't be solved for an input that parsed. Solutions return
+/// this rather than panicking, so that the input's problem is reported.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SolveError {
+    pub message: String,
+}
+
+impl SolveError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+/// A `Solution` with its input and answer types erased, so solutions for
+/// different days can be kept together in a registry. Implemented for every
+/// `Solution`.
+pub trait Solver: Sync {
+    fn day(&self) -> u32;
+
+    fn embedded_input(&self) -> Option<&'static str>;
+
+    fn generate_input(&self, seed: u64, size: usize) -> Option<String>;
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+
+    /// Solve part 1 for input returned by `parse`
+    fn part1(&self, input: &dyn Any) -> Result<Answer, SolveError>;
+
+    /// Solve part 2 for input returned by `parse`
+    fn part2(&self, input: &dyn Any) -> Result<Answer, SolveError>;
+}
+
+impl<S: Solution + Sync> Solver for S {
+    fn day(&self) -> u32 {
+        Solution::day(self)
+    }
+
+    fn embedded_input(&self) -> Option<&'static str> {
+        Solution::embedded_input(self)
+    }
+
+    fn generate_input(&self, seed: u64, size: usize) -> Option<String> {
+        Solution::generate_input(self, seed, size)
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        Solution::parse(self, input).map(|input| Box::new(input) as Box<dyn Any>)
+    }
+
+    fn part1(&self, input: &dyn Any) -> Result<Answer, SolveError> {
+        Solution::part1(self, downcast_input::<S>(input))
+    }
+
+    fn part2(&self, input: &dyn Any) -> Result<Answer, SolveError> {
+        Solution::part2(self, downcast_input::<S>(input))
+    }
+}
+
+fn downcast_input<S: Solution>(input: &dyn Any) -> &S::Input {
+    input
+        .downcast_ref::<S::Input>()
+        .expect("input was not parsed by the same solution")
+}
+
+/// Format a day number the way it's written in crate names and output
+pub fn format_day(day: u32) -> String {
+    format!("{:02}", day)
+}
+
+/// Load the puzzle input for `solver`, solve both parts and print the
+/// answers in the format chosen by `--format` (text, json or jsonl). This is
+/// the whole of a day's `main`.
+pub fn run(solver: &dyn Solver) {
+    let args: Vec<_> = env::args().skip(1).collect();
+    let format = Format::from_args(&args).unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        process::exit(2);
+    });
+
+    let report = match input::load(&format_day(solver.day()), solver.embedded_input()) {
+        Ok(input) => DayReport::solve(solver, &input),
+        Err(e) => DayReport::failed(solver.day(), e.to_string()),
+    };
+
+    let mut printer = Printer::new(format);
+    printer.print(&report);
+    printer.finish();
+    if !report.is_ok() {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct WordCount;
+
+    impl Solution for WordCount {
+        type Input = Vec<String>;
+
+        fn day(&self) -> u32 {
+            3
+        }
+
+        fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+            input
+                .split_ascii_whitespace()
+                .map(|word| match word.parse::<i32>() {
+                    Ok(_) => Err(ParseError::new(input, word, "a word")),
+                    Err(_) => Ok(String::from(word)),
+                })
+                .collect()
+        }
+
+        fn part1(&self, input: &Self::Input) -> Result<Answer, SolveError> {
+            Ok(input.len().into())
+        }
+
+        fn part2(&self, input: &Self::Input) -> Result<Answer, SolveError> {
+            if input.is_empty() {
+                return Err(SolveError::new("there are no words to join"));
+            }
+            Ok(input.concat().into())
+        }
+    }
+
+    #[test]
+    fn test_solver() {
+        let solver: &dyn Solver = &WordCount;
+        assert_eq!(3, solver.day());
+        assert_eq!(None, solver.embedded_input());
+        assert_eq!(None, solver.generate_input(1, 10));
+        let input = solver.parse("a bc d").unwrap();
+        assert_eq!(Ok(Answer::from(3_usize)), solver.part1(input.as_ref()));
+        assert_eq!(Ok(Answer::from("abcd")), solver.part2(input.as_ref()));
+
+        let input = solver.parse("").unwrap();
+        assert_eq!(
+            "there are no words to join",
+            solver.part2(input.as_ref()).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_solver_parse_error() {
+        let solver: &dyn Solver = &WordCount;
+        assert_eq!(
+            "line 2, column 3: expected a word, found \"42\"",
+            solver.parse("a\nb 42").unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_format_day() {
+        assert_eq!("03", format_day(3));
+        assert_eq!("17", format_day(17));
+    }
+}
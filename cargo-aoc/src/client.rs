@@ -0,0 +1,180 @@
+use crate::config::Config;
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+/// An HTTP response from the site
+#[derive(Debug, PartialEq)]
+pub struct Response {
+    pub status: u32,
+    pub body: String,
+}
+
+/// Makes requests to adventofcode.com as the logged in user. The requests
+/// are made with curl, which handles HTTPS for us.
+pub struct Client {
+    base_url: String,
+    session: String,
+    user_agent: String,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Result<Self, String> {
+        Ok(Self {
+            base_url: String::from(config.base_url.trim_end_matches('/')),
+            session: String::from(config.session()?),
+            user_agent: config.user_agent(),
+        })
+    }
+
+    pub fn get(&self, path: &str) -> Result<Response, String> {
+        self.request(path, &[])
+    }
+
+    /// POST `form` to `path` as `application/x-www-form-urlencoded` fields
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<Response, String> {
+        let args: Vec<String> = form
+            .iter()
+            .flat_map(|(name, value)| {
+                [
+                    String::from("--data-urlencode"),
+                    format!("{}={}", name, value),
+                ]
+            })
+            .collect();
+        self.request(path, &args)
+    }
+
+    fn request(&self, path: &str, args: &[String]) -> Result<Response, String> {
+        let mut curl = Command::new("curl");
+        // the session cookie is passed on stdin so it doesn't show up in
+        // the process list
+        curl.args(["--silent", "--show-error", "--header", "@-"])
+            .args(["--user-agent", &self.user_agent])
+            .args(["--write-out", "\n%{http_code}"])
+            .args(args)
+            .arg(format!("{}{}", self.base_url, path));
+
+        let mut child = curl
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("could not run curl: {}", e))?;
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(format!("Cookie: session={}\n", self.session).as_bytes())
+            .map_err(|e| format!("could not write to curl: {}", e))?;
+        let output = child
+            .wait_with_output()
+            .map_err(|e| format!("could not run curl: {}", e))?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "request to {} failed: {}",
+                self.base_url,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        parse_output(&String::from_utf8_lossy(&output.stdout))
+    }
+}
+
+/// Split curl's output into the body and the status code that
+/// `--write-out` adds on the last line
+fn parse_output(output: &str) -> Result<Response, String> {
+    let (body, status) = output
+        .rsplit_once('\n')
+        .ok_or(String::from("curl gave no status code"))?;
+    let status = status
+        .parse()
+        .map_err(|_| format!("curl gave an invalid status code {}", status))?;
+    Ok(Response {
+        status,
+        body: String::from(body),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stub_server::StubServer;
+
+    fn get_test_client(base_url: &str) -> Client {
+        Client::new(&Config {
+            session: Some(String::from("abc123")),
+            base_url: String::from(base_url),
+            contact: Some(String::from("me@example.com")),
+            ..Config::default()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse_output() {
+        assert_eq!(
+            Ok(Response {
+                status: 200,
+                body: String::from("1abc2\npqr3stu8vwx\n"),
+            }),
+            parse_output("1abc2\npqr3stu8vwx\n\n200")
+        );
+        assert!(parse_output("200").is_err());
+    }
+
+    #[test]
+    fn test_get() {
+        let server = StubServer::start(vec![(200, "1abc2\n")]);
+        let client = get_test_client(&format!("{}/", server.base_url));
+
+        assert_eq!(
+            Ok(Response {
+                status: 200,
+                body: String::from("1abc2\n"),
+            }),
+            client.get("/2023/day/1/input")
+        );
+
+        let requests = server.requests();
+        assert!(requests[0].starts_with("GET /2023/day/1/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("\r\nCookie: session=abc123\r\n"));
+        assert!(requests[0].contains("\r\nUser-Agent: cargo-aoc/0.1.0 (+me@example.com)\r\n"));
+    }
+
+    #[test]
+    fn test_post() {
+        let server = StubServer::start(vec![(200, "<article>That's the right answer!</article>")]);
+        let client = get_test_client(&server.base_url);
+
+        let response = client
+            .post("/2023/day/1/answer", &[("level", "1"), ("answer", "a b&c")])
+            .unwrap();
+        assert_eq!(200, response.status);
+
+        let requests = server.requests();
+        assert!(requests[0].starts_with("POST /2023/day/1/answer HTTP/1.1\r\n"));
+        assert!(requests[0].contains("\r\nCookie: session=abc123\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=1&answer=a+b%26c"));
+    }
+
+    #[test]
+    fn test_request_error() {
+        let client = get_test_client("http://127.0.0.1:1");
+        assert!(client
+            .get("/2023/day/1/input")
+            .unwrap_err()
+            .starts_with("request to http://127.0.0.1:1 failed: "));
+    }
+
+    #[test]
+    fn test_no_session() {
+        assert!(Client::new(&Config::default())
+            .err()
+            .unwrap()
+            .starts_with("no session token: set AOC_SESSION"));
+    }
+}
@@ -0,0 +1,164 @@
+use std::{
+    collections::HashMap,
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+/// The environment variable that holds the adventofcode.com session token
+pub const SESSION_ENV: &str = "AOC_SESSION";
+/// The environment variable that overrides the site's base URL, e.g. to
+/// point at a local stand-in server
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const DEFAULT_YEAR: u32 = 2023;
+
+/// Settings for talking to adventofcode.com. They are read from the config
+/// file, `$XDG_CONFIG_HOME/aoc/config` (or `~/.config/aoc/config`), which
+/// holds `key = value` lines:
+///
+/// ```text
+/// session = 53616c7465645f5f...
+/// contact = you@example.com
+/// ```
+///
+/// `AOC_SESSION` and `AOC_BASE_URL` override the file.
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    /// The value of the `session` cookie of a logged in browser
+    pub session: Option<String>,
+    pub base_url: String,
+    pub year: u32,
+    /// Who to contact about the requests, sent in the User-Agent as the
+    /// site asks
+    pub contact: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            session: None,
+            base_url: String::from(DEFAULT_BASE_URL),
+            year: DEFAULT_YEAR,
+            contact: None,
+        }
+    }
+}
+
+impl Config {
+    pub fn load() -> Result<Self, String> {
+        let mut config = match config_path() {
+            Some(path) => Self::read(&path)?,
+            None => Self::default(),
+        };
+
+        if let Ok(session) = env::var(SESSION_ENV) {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = env::var(BASE_URL_ENV) {
+            config.base_url = base_url;
+        }
+        Ok(config)
+    }
+
+    /// Read the config file at `path`. A missing file is the default config.
+    fn read(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|e| format!("{}: {}", path.display(), e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("could not read {}: {}", path.display(), e)),
+        }
+    }
+
+    fn parse(text: &str) -> Result<Self, String> {
+        let mut settings = HashMap::new();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or(format!("line {}: expected key = value", index + 1))?;
+            settings.insert(key.trim(), value.trim().trim_matches('"'));
+        }
+
+        let mut config = Self::default();
+        for (key, value) in settings {
+            match key {
+                "session" => config.session = Some(String::from(value)),
+                "base_url" => config.base_url = String::from(value),
+                "year" => {
+                    config.year = value
+                        .parse()
+                        .map_err(|_| format!("year {} is not a number", value))?
+                }
+                "contact" => config.contact = Some(String::from(value)),
+                _ => return Err(format!("unknown setting {}", key)),
+            }
+        }
+        Ok(config)
+    }
+
+    pub fn session(&self) -> Result<&str, String> {
+        self.session.as_deref().ok_or(format!(
+            "no session token: set {} or add `session = <token>` to {}",
+            SESSION_ENV,
+            config_path().map_or(String::from("the config file"), |p| p.display().to_string())
+        ))
+    }
+
+    pub fn user_agent(&self) -> String {
+        format!(
+            "cargo-aoc/{} (+{})",
+            env!("CARGO_PKG_VERSION"),
+            self.contact.as_deref().unwrap_or("no contact given")
+        )
+    }
+}
+
+fn config_path() -> Option<PathBuf> {
+    let config_dir = env::var("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|_| env::var("HOME").map(|home| Path::new(&home).join(".config")))
+        .ok()?;
+    Some(config_dir.join("aoc").join("config"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let config = Config::parse(
+            "# adventofcode.com\nsession = abc123\ncontact = \"me@example.com\"\n\nyear=2022\n",
+        );
+        assert_eq!(
+            Ok(Config {
+                session: Some(String::from("abc123")),
+                base_url: String::from(DEFAULT_BASE_URL),
+                year: 2022,
+                contact: Some(String::from("me@example.com")),
+            }),
+            config
+        );
+        assert_eq!(Ok(Config::default()), Config::parse(""));
+        assert_eq!(
+            Err(String::from("line 1: expected key = value")),
+            Config::parse("session")
+        );
+        assert_eq!(
+            Err(String::from("unknown setting token")),
+            Config::parse("token = abc")
+        );
+    }
+
+    #[test]
+    fn test_user_agent() {
+        let mut config = Config::default();
+        assert_eq!("cargo-aoc/0.1.0 (+no contact given)", config.user_agent());
+        config.contact = Some(String::from("me@example.com"));
+        assert_eq!("cargo-aoc/0.1.0 (+me@example.com)", config.user_agent());
+    }
+}
@@ -0,0 +1,216 @@
+use aoc_support::{
+    answer::Answer,
+    examples::{format_answers, Example},
+};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Pull the examples out of a saved puzzle page. Each part's description
+/// is an `<article>`; its `<pre><code>` blocks are the examples, and the
+/// last `<code><em>` in it is the answer for the example before it. Part 2
+/// often reuses part 1's example, so its answer goes on the last example
+/// seen, wherever that was.
+pub fn extract_examples(html: &str) -> Vec<Example> {
+    let mut examples: Vec<Example> = vec![];
+    for (part, article) in (1..=2).zip(get_elements(html, "<article", "</article>")) {
+        for block in get_elements(article, "<pre><code>", "</code></pre>") {
+            examples.push(Example {
+                name: format!("example{}", examples.len() + 1),
+                input: to_text(block),
+                part1: None,
+                part2: None,
+            });
+        }
+
+        let answer = get_elements(article, "<code><em>", "</em></code>")
+            .last()
+            .map(|answer| to_text(answer).parse::<Answer>().unwrap());
+        if let (Some(answer), Some(example)) = (answer, examples.last_mut()) {
+            match part {
+                1 => example.part1 = Some(answer),
+                _ => example.part2 = Some(answer),
+            }
+        }
+    }
+    examples
+}
+
+/// Write each example to `<name>.txt` in `examples_dir`, with its answers
+/// in `<name>.answers`, returning the paths written. Existing files are
+/// replaced, so the page can be saved again once part 2 is showing.
+pub fn save_examples(examples_dir: &Path, examples: &[Example]) -> Result<Vec<PathBuf>, String> {
+    let write = |path: PathBuf, contents: &str| {
+        fs::write(&path, contents)
+            .map(|_| path.clone())
+            .map_err(|e| format!("could not write {}: {}", path.display(), e))
+    };
+
+    fs::create_dir_all(examples_dir)
+        .map_err(|e| format!("could not create {}: {}", examples_dir.display(), e))?;
+    let mut paths = vec![];
+    for example in examples {
+        paths.push(write(
+            examples_dir.join(format!("{}.txt", example.name)),
+            &example.input,
+        )?);
+        if example.part1.is_some() || example.part2.is_some() {
+            paths.push(write(
+                examples_dir.join(format!("{}.answers", example.name)),
+                &format_answers(example.part1.as_ref(), example.part2.as_ref()),
+            )?);
+        }
+    }
+    Ok(paths)
+}
+
+/// The contents of every element that starts with `open` and ends with
+/// `close`. `open` may be just the start of a tag, e.g. `<article`, in
+/// which case the rest of the tag is skipped.
+fn get_elements<'a>(html: &'a str, open: &str, close: &str) -> Vec<&'a str> {
+    let mut elements = vec![];
+    let mut rest = html;
+    while let Some(start) = rest.find(open) {
+        rest = &rest[start + open.len()..];
+        if !open.ends_with('>') {
+            let Some(tag_end) = rest.find('>') else {
+                break;
+            };
+            rest = &rest[tag_end + 1..];
+        }
+        let Some(end) = rest.find(close) else {
+            break;
+        };
+        elements.push(&rest[..end]);
+        rest = &rest[end + close.len()..];
+    }
+    elements
+}
+
+/// Strip the tags out of some HTML (examples highlight parts of
+/// themselves with `<em>`) and decode the entities it escapes text with
+fn to_text(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_support::examples::load_examples;
+    use std::{env, process};
+
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us">
+<body>
+<main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2>
+<p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+</code></pre>
+<p>Adding these together produces <code><em>142</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>54331</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Equipped with this new information, you now need to find the real first and last digit on each line. For example:</p>
+<pre><code>two1nine
+eightwothree
+<em>7</em>pqrstsixteen
+</code></pre>
+<p>In this example, the calibration values are <code>29</code>, <code>83</code> and <code>76</code>. Adding these together produces <code><em>281</em></code>.</p>
+</article>
+</main>
+</body>
+</html>"#;
+
+    #[test]
+    fn test_extract_examples() {
+        assert_eq!(
+            vec![
+                Example {
+                    name: String::from("example1"),
+                    input: String::from("1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n"),
+                    part1: Some(Answer::from(142)),
+                    part2: None,
+                },
+                Example {
+                    name: String::from("example2"),
+                    input: String::from("two1nine\neightwothree\n7pqrstsixteen\n"),
+                    part1: None,
+                    part2: Some(Answer::from(281)),
+                },
+            ],
+            extract_examples(PAGE)
+        );
+    }
+
+    #[test]
+    fn test_extract_examples_part2_reuses_example() {
+        let page = "<article><pre><code>Time: 7 15\nDistance: 9 40\n</code></pre>\
+            <p>Multiply these together to get <code><em>288</em></code>.</p></article>\
+            <article><p>Now there is only one race, so the answer is <code><em>71503</em></code>.</p></article>";
+        assert_eq!(
+            vec![Example {
+                name: String::from("example1"),
+                input: String::from("Time: 7 15\nDistance: 9 40\n"),
+                part1: Some(Answer::from(288)),
+                part2: Some(Answer::from(71503)),
+            }],
+            extract_examples(page)
+        );
+        assert_eq!(
+            Vec::<Example>::new(),
+            extract_examples("<article>no examples</article>")
+        );
+    }
+
+    #[test]
+    fn test_to_text() {
+        assert_eq!(
+            "a<b> & \"c\"",
+            to_text("<em>a</em>&lt;b&gt; &amp; &quot;c&quot;")
+        );
+        assert_eq!("&lt;", to_text("&amp;lt;"));
+    }
+
+    #[test]
+    fn test_save_examples() {
+        let crate_dir = env::temp_dir().join(format!("cargo-aoc-examples-{}", process::id()));
+        let examples_dir = crate_dir.join("examples");
+        let _ = fs::remove_dir_all(&crate_dir);
+
+        let examples = extract_examples(PAGE);
+        assert_eq!(
+            Ok(vec![
+                examples_dir.join("example1.txt"),
+                examples_dir.join("example1.answers"),
+                examples_dir.join("example2.txt"),
+                examples_dir.join("example2.answers"),
+            ]),
+            save_examples(&examples_dir, &examples)
+        );
+        assert_eq!(
+            "part2 = 281\n",
+            fs::read_to_string(examples_dir.join("example2.answers")).unwrap()
+        );
+        assert_eq!(examples, load_examples(crate_dir.to_str().unwrap()));
+        fs::remove_dir_all(&crate_dir).unwrap();
+    }
+}
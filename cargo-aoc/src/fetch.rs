@@ -0,0 +1,147 @@
+use crate::client::Client;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// What fetching a day's input did
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    /// The input was downloaded and saved at the path
+    Downloaded(PathBuf),
+    /// The input had already been saved at the path, so nothing was
+    /// downloaded
+    Cached(PathBuf),
+}
+
+/// Download the puzzle input for `day` into `dayNN.txt` in `input_dir`,
+/// unless it is already there. An empty file, like the one made by
+/// `cargo aoc new`, doesn't count as a saved input.
+pub fn fetch_input(
+    client: &Client,
+    year: u32,
+    input_dir: &Path,
+    day: u32,
+) -> Result<Fetched, String> {
+    let path = input_dir.join(format!("day{:02}.txt", day));
+    if fs::metadata(&path).is_ok_and(|m| m.len() > 0) {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let response = client.get(&format!("/{}/day/{}/input", year, day))?;
+    match response.status {
+        200 => {
+            fs::create_dir_all(input_dir)
+                .and_then(|_| fs::write(&path, response.body))
+                .map_err(|e| format!("could not write {}: {}", path.display(), e))?;
+            Ok(Fetched::Downloaded(path))
+        }
+        404 => Err(format!(
+            "the input for {} day {} is not available yet",
+            year, day
+        )),
+        400 | 500 => Err(String::from(
+            "the session token was rejected; it may have expired",
+        )),
+        status => Err(format!(
+            "unexpected response {}: {}",
+            status,
+            response.body.trim()
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{config::Config, stub_server::StubServer};
+    use std::{env, process};
+
+    fn get_test_client(base_url: &str) -> Client {
+        Client::new(&Config {
+            session: Some(String::from("abc123")),
+            base_url: String::from(base_url),
+            ..Config::default()
+        })
+        .unwrap()
+    }
+
+    fn get_test_input_dir(name: &str) -> PathBuf {
+        let input_dir = env::temp_dir().join(format!("cargo-aoc-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&input_dir);
+        input_dir
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let input_dir = get_test_input_dir("fetch");
+        let server = StubServer::start(vec![(200, "1abc2\npqr3stu8vwx\n")]);
+        let client = get_test_client(&server.base_url);
+        let path = input_dir.join("day01.txt");
+
+        assert_eq!(
+            Ok(Fetched::Downloaded(path.clone())),
+            fetch_input(&client, 2023, &input_dir, 1)
+        );
+        assert_eq!("1abc2\npqr3stu8vwx\n", fs::read_to_string(&path).unwrap());
+
+        // the stub only answers once, so a second download would fail
+        assert_eq!(
+            Ok(Fetched::Cached(path)),
+            fetch_input(&client, 2023, &input_dir, 1)
+        );
+
+        let requests = server.requests();
+        assert_eq!(1, requests.len());
+        assert!(requests[0].starts_with("GET /2023/day/1/input HTTP/1.1\r\n"));
+        fs::remove_dir_all(&input_dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_replaces_empty_input() {
+        let input_dir = get_test_input_dir("fetch-empty");
+        fs::create_dir_all(&input_dir).unwrap();
+        fs::write(input_dir.join("day17.txt"), "").unwrap();
+        let server = StubServer::start(vec![(200, "2413\n")]);
+
+        assert_eq!(
+            Ok(Fetched::Downloaded(input_dir.join("day17.txt"))),
+            fetch_input(&get_test_client(&server.base_url), 2023, &input_dir, 17)
+        );
+        assert_eq!(
+            "2413\n",
+            fs::read_to_string(input_dir.join("day17.txt")).unwrap()
+        );
+        fs::remove_dir_all(&input_dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let input_dir = get_test_input_dir("fetch-errors");
+        let server = StubServer::start(vec![
+            (404, "Not Found"),
+            (400, "Puzzle inputs differ by user."),
+            (503, "Service Unavailable\n"),
+        ]);
+        let client = get_test_client(&server.base_url);
+
+        assert_eq!(
+            Err(String::from(
+                "the input for 2023 day 25 is not available yet"
+            )),
+            fetch_input(&client, 2023, &input_dir, 25)
+        );
+        assert_eq!(
+            Err(String::from(
+                "the session token was rejected; it may have expired"
+            )),
+            fetch_input(&client, 2023, &input_dir, 2)
+        );
+        assert_eq!(
+            Err(String::from("unexpected response 503: Service Unavailable")),
+            fetch_input(&client, 2023, &input_dir, 2)
+        );
+        assert!(!input_dir.exists());
+        server.requests();
+    }
+}
@@ -0,0 +1,354 @@
+mod client;
+mod config;
+mod examples;
+mod fetch;
+mod scaffold;
+#[cfg(test)]
+mod stub_server;
+mod submit;
+mod workspace;
+
+use aoc_support::answers::{AnswerStore, ANSWERS_FILE};
+use client::Client;
+use config::Config;
+use fetch::Fetched;
+use std::{
+    env, fs,
+    path::Path,
+    process,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use submit::{Outcome, SubmissionLog, SUBMISSIONS_FILE};
+use workspace::Workspace;
+
+const USAGE: &str = "usage: cargo aoc <NN|all> [1|2]
+       cargo aoc new <NN>
+       cargo aoc fetch <NN|all>
+       cargo aoc examples <NN> <page.html>
+       cargo aoc submit <NN> <1|2>";
+
+fn main() {
+    if let Err(message) = run(env::args().skip(1).collect()) {
+        eprintln!("error: {}", message);
+        process::exit(1);
+    }
+}
+
+fn run(args: Vec<String>) -> Result<(), String> {
+    let workspace = Workspace::find(&env::current_dir().map_err(|e| e.to_string())?)?;
+    let command = Command::parse(&args)?;
+
+    match command {
+        Command::Run { day, part } => {
+            let day_crate = workspace.get_day_crate(&day)?;
+            run_day(&workspace, &day_crate, part)
+        }
+        Command::RunAll { part } => {
+            for day_crate in workspace.days.iter() {
+                run_day(&workspace, day_crate, part)?;
+            }
+            Ok(())
+        }
+        Command::New { day } => {
+            scaffold::create_day(&workspace, &workspace::get_day_crate_name(&day)?)
+        }
+        Command::Fetch { day } => {
+            let days = if day == "all" {
+                workspace
+                    .days
+                    .iter()
+                    .map(|day_crate| workspace::get_day_number(&day_crate[3..]))
+                    .collect::<Result<_, _>>()?
+            } else {
+                vec![workspace::get_day_number(&day)?]
+            };
+            fetch_days(&workspace, &days)
+        }
+        Command::Examples { day, page } => {
+            let day_crate = workspace.get_day_crate(&day)?;
+            save_examples(&workspace, &day_crate, Path::new(&page))
+        }
+        Command::Submit { day, part } => {
+            let day_crate = workspace.get_day_crate(&day)?;
+            submit_day(&workspace, &day_crate, part)
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Run { day: String, part: Option<u32> },
+    RunAll { part: Option<u32> },
+    New { day: String },
+    Fetch { day: String },
+    Examples { day: String, page: String },
+    Submit { day: String, part: u32 },
+}
+
+impl Command {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        // cargo runs us as `cargo-aoc aoc <args>`, but we can also be run
+        // directly as `cargo-aoc <args>`
+        let args = match args.first() {
+            Some(arg) if arg == "aoc" => &args[1..],
+            _ => args,
+        };
+
+        match (args.first().map(|a| a.as_str()), args.get(1)) {
+            (Some("new"), Some(day)) if args.len() == 2 => {
+                return Ok(Command::New { day: day.clone() })
+            }
+            (Some("fetch"), Some(day)) if args.len() == 2 => {
+                return Ok(Command::Fetch { day: day.clone() })
+            }
+            (Some("examples"), Some(day)) if args.len() == 3 => {
+                return Ok(Command::Examples {
+                    day: day.clone(),
+                    page: args[2].clone(),
+                })
+            }
+            (Some("submit"), Some(day)) if args.len() == 3 => {
+                return Ok(Command::Submit {
+                    day: day.clone(),
+                    part: parse_part(&args[2])?,
+                })
+            }
+            (Some("new" | "fetch" | "examples" | "submit"), _) => return Err(String::from(USAGE)),
+            _ => (),
+        }
+
+        let part = args.get(1).map(|p| parse_part(p)).transpose()?;
+
+        match args.first().map(|a| a.as_str()) {
+            None => Err(String::from(USAGE)),
+            Some("all") => Ok(Command::RunAll { part }),
+            Some(day) => Ok(Command::Run {
+                day: String::from(day),
+                part,
+            }),
+        }
+    }
+}
+
+fn parse_part(part: &str) -> Result<u32, String> {
+    match part {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("{} is not a part (1 or 2)\n{}", part, USAGE)),
+    }
+}
+
+/// Build and run a day's binary, echoing the answers it prints for the
+/// requested part (or everything it prints if no part was requested).
+fn run_day(workspace: &Workspace, day_crate: &str, part: Option<u32>) -> Result<(), String> {
+    let (stdout, result) = run_day_binary(workspace, day_crate)?;
+    for line in stdout.lines() {
+        match part {
+            Some(part) if !is_answer_line(line, day_crate, part) => (),
+            _ => println!("{}", line),
+        }
+    }
+    result
+}
+
+/// Build and run a day's binary, returning what it printed and whether it
+/// succeeded
+fn run_day_binary(
+    workspace: &Workspace,
+    day_crate: &str,
+) -> Result<(String, Result<(), String>), String> {
+    let output = process::Command::new(env::var("CARGO").unwrap_or(String::from("cargo")))
+        .args(["run", "--release", "--quiet", "-p", day_crate])
+        .current_dir(&workspace.root)
+        .stderr(process::Stdio::inherit())
+        .output()
+        .map_err(|e| format!("could not run cargo: {}", e))?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+    if output.status.success() {
+        Ok((stdout, Ok(())))
+    } else {
+        Ok((
+            stdout,
+            Err(format!("{} failed: {}", day_crate, output.status)),
+        ))
+    }
+}
+
+/// Save the examples in a saved puzzle page to the day's `examples`
+/// directory, for its tests to load
+fn save_examples(workspace: &Workspace, day_crate: &str, page: &Path) -> Result<(), String> {
+    let html = fs::read_to_string(page)
+        .map_err(|e| format!("could not read {}: {}", page.display(), e))?;
+    let examples = examples::extract_examples(&html);
+    if examples.is_empty() {
+        return Err(format!("{} has no examples", page.display()));
+    }
+
+    let examples_dir = workspace.root.join(day_crate).join("examples");
+    for path in examples::save_examples(&examples_dir, &examples)? {
+        println!("Saved {}", path.display());
+    }
+    Ok(())
+}
+
+/// Run a day's binary to get its answer to `part`, and submit it. A right
+/// answer is also saved for `aoc verify`.
+fn submit_day(workspace: &Workspace, day_crate: &str, part: u32) -> Result<(), String> {
+    let (stdout, result) = run_day_binary(workspace, day_crate)?;
+    result?;
+    let answer = stdout
+        .lines()
+        .find(|line| is_answer_line(line, day_crate, part))
+        .and_then(|line| line.split_once(": "))
+        .map(|(_, answer)| answer.parse().unwrap())
+        .ok_or(format!("{} printed no answer to part {}", day_crate, part))?;
+
+    let config = Config::load()?;
+    let client = Client::new(&config)?;
+    let input_dir = workspace.input_dir();
+    let mut log = SubmissionLog::load(&input_dir.join(SUBMISSIONS_FILE))?;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| e.to_string())?
+        .as_secs();
+
+    let day = workspace::get_day_number(&day_crate[3..])?;
+    let outcome = submit::submit_answer(&client, config.year, &mut log, day, part, &answer, now)?;
+    println!(
+        "Day {} Part {}: {} is {}",
+        &day_crate[3..],
+        part,
+        answer,
+        outcome
+    );
+
+    if outcome == Outcome::Right {
+        let answers_path = input_dir.join(ANSWERS_FILE);
+        let mut answers = AnswerStore::load(&answers_path)?;
+        answers.insert(day, part, answer);
+        answers.save(&answers_path)?;
+    }
+    Ok(())
+}
+
+/// Download the puzzle inputs for `days` that haven't already been fetched
+fn fetch_days(workspace: &Workspace, days: &[u32]) -> Result<(), String> {
+    let config = Config::load()?;
+    let client = Client::new(&config)?;
+    let input_dir = workspace.input_dir();
+
+    for day in days {
+        match fetch::fetch_input(&client, config.year, &input_dir, *day)? {
+            Fetched::Downloaded(path) => println!("Saved day {} to {}", day, path.display()),
+            Fetched::Cached(path) => {
+                println!("Day {} is already saved in {}", day, path.display())
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Is this the "Day NN Part N: answer" line printed by a day's `main`?
+fn is_answer_line(line: &str, day_crate: &str, part: u32) -> bool {
+    line.starts_with(&format!("Day {} Part {}:", &day_crate[3..], part))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_support::test_support::TestCase;
+
+    fn to_args(args: &str) -> Vec<String> {
+        args.split_ascii_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_command() {
+        let test_cases = [
+            TestCase {
+                input: "aoc 01",
+                expected: Ok(Command::Run {
+                    day: String::from("01"),
+                    part: None,
+                }),
+            },
+            TestCase {
+                input: "17 2",
+                expected: Ok(Command::Run {
+                    day: String::from("17"),
+                    part: Some(2),
+                }),
+            },
+            TestCase {
+                input: "aoc all 1",
+                expected: Ok(Command::RunAll { part: Some(1) }),
+            },
+            TestCase {
+                input: "aoc new 7",
+                expected: Ok(Command::New {
+                    day: String::from("7"),
+                }),
+            },
+            TestCase {
+                input: "aoc new",
+                expected: Err(String::from(USAGE)),
+            },
+            TestCase {
+                input: "aoc fetch 5",
+                expected: Ok(Command::Fetch {
+                    day: String::from("5"),
+                }),
+            },
+            TestCase {
+                input: "aoc fetch 5 6",
+                expected: Err(String::from(USAGE)),
+            },
+            TestCase {
+                input: "aoc examples 3 day3.html",
+                expected: Ok(Command::Examples {
+                    day: String::from("3"),
+                    page: String::from("day3.html"),
+                }),
+            },
+            TestCase {
+                input: "aoc examples 3",
+                expected: Err(String::from(USAGE)),
+            },
+            TestCase {
+                input: "aoc submit 6 2",
+                expected: Ok(Command::Submit {
+                    day: String::from("6"),
+                    part: 2,
+                }),
+            },
+            TestCase {
+                input: "aoc submit 6",
+                expected: Err(String::from(USAGE)),
+            },
+            TestCase {
+                input: "aoc submit 6 3",
+                expected: Err(format!("3 is not a part (1 or 2)\n{}", USAGE)),
+            },
+            TestCase {
+                input: "aoc",
+                expected: Err(String::from(USAGE)),
+            },
+            TestCase {
+                input: "aoc 01 3",
+                expected: Err(format!("3 is not a part (1 or 2)\n{}", USAGE)),
+            },
+        ];
+        for TestCase { input, expected } in test_cases.iter() {
+            assert_eq!(Command::parse(&to_args(input)), *expected);
+        }
+    }
+
+    #[test]
+    fn test_is_answer_line() {
+        assert!(is_answer_line("Day 01 Part 1: 142", "day01", 1));
+        assert!(!is_answer_line("Day 01 Part 2: 281", "day01", 1));
+        assert!(!is_answer_line("Day 17 Part 1: 102", "day01", 1));
+        assert!(!is_answer_line("13x13", "day17", 1));
+    }
+}
@@ -0,0 +1,219 @@
+use crate::workspace::{get_members, Workspace};
+use std::fs;
+
+const CARGO_TOML_TEMPLATE: &str = include_str!("../templates/Cargo.toml.tmpl");
+const LIB_RS_TEMPLATE: &str = include_str!("../templates/lib.rs.tmpl");
+const MAIN_RS_TEMPLATE: &str = include_str!("../templates/main.rs.tmpl");
+const README_TEMPLATE: &str = include_str!("../templates/README.md.tmpl");
+
+/// Create the dayNN crate for a new day's problem, along with an empty
+/// puzzle input file, add it to the workspace members and register its
+/// solution with the `aoc` crate.
+pub fn create_day(workspace: &Workspace, day_crate: &str) -> Result<(), String> {
+    let manifest_path = workspace.root.join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path).map_err(|e| e.to_string())?;
+
+    let day_dir = workspace.root.join(day_crate);
+    if day_dir.exists() || get_members(&manifest).iter().any(|m| m == day_crate) {
+        return Err(format!("{} already exists", day_crate));
+    }
+
+    let manifest = add_member(&manifest, day_crate)?;
+
+    let registry_manifest_path = workspace.root.join("aoc").join("Cargo.toml");
+    let registry_manifest =
+        fs::read_to_string(&registry_manifest_path).map_err(|e| e.to_string())?;
+    let registry_manifest = insert_line_sorted(
+        &registry_manifest,
+        "[dependencies]",
+        &format!("{} = {{path = \"../{}\"}}", day_crate, day_crate),
+    )?;
+
+    let registry_path = workspace.root.join("aoc").join("src").join("lib.rs");
+    let registry = fs::read_to_string(&registry_path).map_err(|e| e.to_string())?;
+    let registry = insert_line_sorted(
+        &registry,
+        "pub const SOLUTIONS: &[&dyn Solver] = &[",
+        &render("    &day{{NN}}::Day{{NN}},", day_crate),
+    )?;
+
+    fs::create_dir_all(day_dir.join("src")).map_err(|e| e.to_string())?;
+    fs::write(
+        day_dir.join("Cargo.toml"),
+        render(CARGO_TOML_TEMPLATE, day_crate),
+    )
+    .map_err(|e| e.to_string())?;
+    fs::write(
+        day_dir.join("src").join("lib.rs"),
+        render(LIB_RS_TEMPLATE, day_crate),
+    )
+    .map_err(|e| e.to_string())?;
+    fs::write(
+        day_dir.join("src").join("main.rs"),
+        render(MAIN_RS_TEMPLATE, day_crate),
+    )
+    .map_err(|e| e.to_string())?;
+    fs::write(
+        day_dir.join("README.md"),
+        render(README_TEMPLATE, day_crate),
+    )
+    .map_err(|e| e.to_string())?;
+
+    // the puzzle input is filled in by hand, so never clobber one that's
+    // already been saved
+    let inputs_dir = workspace.root.join("inputs");
+    let input_path = inputs_dir.join(format!("{}.txt", day_crate));
+    if !input_path.exists() {
+        fs::create_dir_all(&inputs_dir).map_err(|e| e.to_string())?;
+        fs::write(&input_path, "").map_err(|e| e.to_string())?;
+    }
+
+    fs::write(&manifest_path, manifest).map_err(|e| e.to_string())?;
+    fs::write(&registry_manifest_path, registry_manifest).map_err(|e| e.to_string())?;
+    fs::write(&registry_path, registry).map_err(|e| e.to_string())?;
+
+    println!("Created {}", day_crate);
+    Ok(())
+}
+
+/// Fill in the day number placeholders of a template. `{{NN}}` is the
+/// zero-padded day number and `{{N}}` is the plain day number.
+fn render(template: &str, day_crate: &str) -> String {
+    let padded_day = &day_crate[3..];
+    let day = padded_day.trim_start_matches('0');
+    template.replace("{{NN}}", padded_day).replace("{{N}}", day)
+}
+
+/// Return the manifest with `member` inserted into the workspace members
+/// list, keeping the list sorted.
+fn add_member(manifest: &str, member: &str) -> Result<String, String> {
+    let members_start = manifest
+        .find("members")
+        .ok_or("workspace has no members list")?;
+    let list_start = members_start
+        + manifest[members_start..]
+            .find('[')
+            .ok_or("workspace members list is malformed")?;
+    let list_end = list_start
+        + manifest[list_start..]
+            .find(']')
+            .ok_or("workspace members list is malformed")?;
+
+    let mut members = get_members(manifest);
+    let index = members
+        .iter()
+        .position(|m| m.as_str() > member)
+        .unwrap_or(members.len());
+    members.insert(index, String::from(member));
+
+    let mut new_manifest = String::from(&manifest[..=list_start]);
+    new_manifest.push('\n');
+    for m in members {
+        new_manifest.push_str(&format!("  \"{}\",\n", m));
+    }
+    new_manifest.push_str(&manifest[list_end..]);
+
+    Ok(new_manifest)
+}
+
+/// Return `text` with `line` inserted into the sorted block of lines that
+/// follows the line `block_header`. The block ends at a blank line, a line
+/// closing a list, or the end of the text.
+fn insert_line_sorted(text: &str, block_header: &str, line: &str) -> Result<String, String> {
+    let mut lines: Vec<_> = text.lines().collect();
+    let header_index = lines
+        .iter()
+        .position(|l| l.trim() == block_header)
+        .ok_or(format!("could not find `{}`", block_header))?;
+
+    let mut index = header_index + 1;
+    while index < lines.len() {
+        let l = lines[index];
+        if l.trim().is_empty() || l.trim_start().starts_with(']') || l > line {
+            break;
+        }
+        index += 1;
+    }
+    lines.insert(index, line);
+
+    let mut new_text = lines.join("\n");
+    if text.ends_with('\n') {
+        new_text.push('\n');
+    }
+    Ok(new_text)
+}
+
+#[cfg(test)]
+mod tests {
+    const TEST_MANIFEST: &str = r#"[workspace]
+resolver = "2"
+
+members = [
+  "cargo-aoc",
+  "day01",
+  "day17",
+  "test_support",
+]
+"#;
+
+    use super::*;
+
+    #[test]
+    fn test_add_member() {
+        assert_eq!(
+            Ok(String::from(
+                r#"[workspace]
+resolver = "2"
+
+members = [
+  "cargo-aoc",
+  "day01",
+  "day07",
+  "day17",
+  "test_support",
+]
+"#
+            )),
+            add_member(TEST_MANIFEST, "day07")
+        );
+        assert_eq!(
+            Ok(String::from("members = [\n  \"day01\",\n  \"day25\",\n]")),
+            add_member("members = [\"day01\"]", "day25")
+        );
+        assert!(add_member("[package]", "day07").is_err());
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            "day07 [2023 Day 07](https://adventofcode.com/2023/day/7)",
+            render(
+                "day{{NN}} [2023 Day {{NN}}](https://adventofcode.com/2023/day/{{N}})",
+                "day07"
+            )
+        );
+        assert!(render(LIB_RS_TEMPLATE, "day12").contains("impl Solution for Day12 {"));
+        assert!(render(MAIN_RS_TEMPLATE, "day12").contains("run(&day12::Day12)"));
+        assert!(render(CARGO_TOML_TEMPLATE, "day12").contains("name = \"day12\""));
+    }
+
+    #[test]
+    fn test_insert_line_sorted() {
+        let registry = "use aoc_support::solution::Solver;\n\npub const SOLUTIONS: &[&dyn Solver] = &[\n    &day01::Day01,\n    &day17::Day17,\n];\n";
+        assert_eq!(
+            Ok(String::from("use aoc_support::solution::Solver;\n\npub const SOLUTIONS: &[&dyn Solver] = &[\n    &day01::Day01,\n    &day07::Day07,\n    &day17::Day17,\n];\n")),
+            insert_line_sorted(registry, "pub const SOLUTIONS: &[&dyn Solver] = &[", "    &day07::Day07,")
+        );
+        assert_eq!(
+            Ok(String::from("use aoc_support::solution::Solver;\n\npub const SOLUTIONS: &[&dyn Solver] = &[\n    &day01::Day01,\n    &day17::Day17,\n    &day25::Day25,\n];\n")),
+            insert_line_sorted(registry, "pub const SOLUTIONS: &[&dyn Solver] = &[", "    &day25::Day25,")
+        );
+
+        let manifest = "[package]\nname = \"aoc\"\n\n[dependencies]\naoc_support = {path = \"../aoc_support\"}\nday01 = {path = \"../day01\"}\ntest_support = {path = \"../test_support\"}";
+        assert_eq!(
+            Ok(String::from("[package]\nname = \"aoc\"\n\n[dependencies]\naoc_support = {path = \"../aoc_support\"}\nday01 = {path = \"../day01\"}\nday07 = {path = \"../day07\"}\ntest_support = {path = \"../test_support\"}")),
+            insert_line_sorted(manifest, "[dependencies]", "day07 = {path = \"../day07\"}")
+        );
+        assert!(insert_line_sorted(manifest, "[dev-dependencies]", "day07").is_err());
+    }
+}
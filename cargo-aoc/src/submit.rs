@@ -0,0 +1,552 @@
+use crate::client::Client;
+use aoc_support::{
+    answer::Answer,
+    answers::{format_value, parse_value},
+};
+use std::{
+    fmt,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// The file in the input directory that every submission is logged to
+pub const SUBMISSIONS_FILE: &str = "submissions.log";
+
+/// How long the site makes us wait after a wrong answer before it accepts
+/// another one
+const WRONG_ANSWER_WAIT: u64 = 60;
+
+/// What the site said about a submitted answer
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// An answer was submitted too recently; this many seconds are left to
+    /// wait
+    Wait(u64),
+    /// The part has already been solved, so the answer wasn't checked
+    AlreadySolved,
+}
+
+impl Outcome {
+    /// Read the outcome out of the page the site returns for a submission
+    pub fn from_response(body: &str) -> Result<Self, String> {
+        let message = get_article(body);
+        if message.contains("That's the right answer") {
+            Ok(Outcome::Right)
+        } else if message.contains("That's not the right answer") {
+            if message.contains("your answer is too high") {
+                Ok(Outcome::TooHigh)
+            } else if message.contains("your answer is too low") {
+                Ok(Outcome::TooLow)
+            } else {
+                Ok(Outcome::Wrong)
+            }
+        } else if message.contains("You gave an answer too recently") {
+            get_wait(message)
+                .map(Outcome::Wait)
+                .ok_or(format!("could not read the time left to wait: {}", message))
+        } else if message.contains("Did you already complete it?") {
+            Ok(Outcome::AlreadySolved)
+        } else {
+            Err(format!("could not understand the response: {}", message))
+        }
+    }
+
+    fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Right => write!(f, "right"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::TooHigh => write!(f, "too high"),
+            Outcome::TooLow => write!(f, "too low"),
+            Outcome::Wait(seconds) => write!(f, "wait {}s", seconds),
+            Outcome::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "right" => Ok(Outcome::Right),
+            "wrong" => Ok(Outcome::Wrong),
+            "too high" => Ok(Outcome::TooHigh),
+            "too low" => Ok(Outcome::TooLow),
+            "already solved" => Ok(Outcome::AlreadySolved),
+            _ => s
+                .strip_prefix("wait ")
+                .and_then(|s| s.strip_suffix('s'))
+                .and_then(|seconds| seconds.parse().ok())
+                .map(Outcome::Wait)
+                .ok_or(format!("{} is not an outcome", s)),
+        }
+    }
+}
+
+/// The message is in the page's `<article>`; fall back to the whole body
+/// if there isn't one
+fn get_article(body: &str) -> &str {
+    body.split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(body, |(article, _)| article)
+}
+
+/// Read "You have 1m 5s left to wait" as 65 seconds
+fn get_wait(message: &str) -> Option<u64> {
+    let (_, rest) = message.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+    wait.split_whitespace().try_fold(0_u64, |total, unit| {
+        let seconds = if let Some(minutes) = unit.strip_suffix('m') {
+            minutes.parse::<u64>().ok()?.checked_mul(60)?
+        } else {
+            unit.strip_suffix('s')?.parse().ok()?
+        };
+        total.checked_add(seconds)
+    })
+}
+
+/// One submitted answer
+#[derive(Debug, PartialEq)]
+pub struct Attempt {
+    /// When it was submitted, in seconds since the Unix epoch
+    pub time: u64,
+    pub day: u32,
+    pub part: u32,
+    pub answer: Answer,
+    pub outcome: Outcome,
+}
+
+/// Every answer we have submitted, kept so we don't submit an answer we
+/// already know is wrong or submit before the site will accept one. It is
+/// saved as tab separated `time day part answer outcome` lines, with the
+/// answer written the way `answers.toml` writes it: text quoted and
+/// escaped, so that it can't hold a tab or newline and isn't read back as
+/// a number.
+pub struct SubmissionLog {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl SubmissionLog {
+    /// Load the log saved at `path`. A missing file is an empty log.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let attempts = match fs::read_to_string(path) {
+            Ok(text) => parse_log(&text).map_err(|e| format!("{}: {}", path.display(), e))?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => vec![],
+            Err(e) => return Err(format!("could not read {}: {}", path.display(), e)),
+        };
+        Ok(Self {
+            path: path.to_path_buf(),
+            attempts,
+        })
+    }
+
+    /// Add an attempt to the log, saving it straight away
+    pub fn append(&mut self, attempt: Attempt) -> Result<(), String> {
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| {
+                writeln!(
+                    file,
+                    "{}\t{}\t{}\t{}\t{}",
+                    attempt.time,
+                    attempt.day,
+                    attempt.part,
+                    format_value(&attempt.answer),
+                    attempt.outcome
+                )
+            })
+            .map_err(|e| format!("could not write {}: {}", self.path.display(), e))?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+
+    /// Check whether `answer` is worth submitting at `now`, given what the
+    /// site has already told us
+    pub fn check(&self, day: u32, part: u32, answer: &Answer, now: u64) -> Result<(), String> {
+        let attempts = self
+            .attempts
+            .iter()
+            .filter(|a| a.day == day && a.part == part);
+        for attempt in attempts {
+            match attempt.outcome {
+                Outcome::Right => {
+                    return Err(format!(
+                        "day {} part {} was already solved with {}",
+                        day, part, attempt.answer
+                    ))
+                }
+                _ if attempt.outcome.is_wrong() && attempt.answer == *answer => {
+                    return Err(format!(
+                        "{} was already submitted for day {} part {} and was {}",
+                        answer, day, part, attempt.outcome
+                    ))
+                }
+                Outcome::TooHigh if is_at_least(answer, &attempt.answer) => {
+                    return Err(format!(
+                        "{} is too high: {} already was",
+                        answer, attempt.answer
+                    ))
+                }
+                Outcome::TooLow if is_at_least(&attempt.answer, answer) => {
+                    return Err(format!(
+                        "{} is too low: {} already was",
+                        answer, attempt.answer
+                    ))
+                }
+                _ => (),
+            }
+        }
+
+        // the site throttles submissions for every puzzle, not just the one
+        // the answer was for
+        let wait_until = match self.attempts.last() {
+            Some(
+                last @ Attempt {
+                    outcome: Outcome::Wait(seconds),
+                    ..
+                },
+            ) => last.time + seconds,
+            Some(last) if last.outcome.is_wrong() => last.time + WRONG_ANSWER_WAIT,
+            _ => 0,
+        };
+        if now < wait_until {
+            return Err(format!(
+                "wait {}s before submitting another answer",
+                wait_until - now
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn parse_log(text: &str) -> Result<Vec<Attempt>, String> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(index, line)| {
+            let fields: Vec<&str> = line.split('\t').collect();
+            let [time, day, part, answer, outcome] = fields[..] else {
+                return Err(format!("line {}: expected 5 fields", index + 1));
+            };
+            let number = |field: &str| {
+                field
+                    .parse()
+                    .map_err(|_| format!("line {}: {} is not a number", index + 1, field))
+            };
+            Ok(Attempt {
+                time: number(time)?,
+                day: number(day)? as u32,
+                part: number(part)? as u32,
+                answer: parse_value(answer).map_err(|e| format!("line {}: {}", index + 1, e))?,
+                outcome: outcome
+                    .parse()
+                    .map_err(|e| format!("line {}: {}", index + 1, e))?,
+            })
+        })
+        .collect()
+}
+
+/// Is `a` an integer at least as large as the integer `b`?
+fn is_at_least(a: &Answer, b: &Answer) -> bool {
+    match (i128::try_from(a), i128::try_from(b)) {
+        (Ok(a), Ok(b)) => a >= b,
+        _ => false,
+    }
+}
+
+/// Submit `answer` for `part` of `day`, unless the log shows it can't be
+/// right or the site won't accept it yet, and log what the site said
+pub fn submit_answer(
+    client: &Client,
+    year: u32,
+    log: &mut SubmissionLog,
+    day: u32,
+    part: u32,
+    answer: &Answer,
+    now: u64,
+) -> Result<Outcome, String> {
+    log.check(day, part, answer, now)?;
+
+    let response = client.post(
+        &format!("/{}/day/{}/answer", year, day),
+        &[
+            ("level", &part.to_string()),
+            ("answer", &answer.to_string()),
+        ],
+    )?;
+    if response.status != 200 {
+        return Err(format!(
+            "unexpected response {}: {}",
+            response.status,
+            response.body.trim()
+        ));
+    }
+
+    let outcome = Outcome::from_response(&response.body)?;
+    log.append(Attempt {
+        time: now,
+        day,
+        part,
+        answer: answer.clone(),
+        outcome: outcome.clone(),
+    })?;
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{config::Config, stub_server::StubServer};
+    use std::{env, process};
+    use test_support::test_support::TestCase;
+
+    fn get_test_log(name: &str) -> SubmissionLog {
+        let path = env::temp_dir().join(format!("cargo-aoc-{}-{}.log", name, process::id()));
+        let _ = fs::remove_file(&path);
+        SubmissionLog::load(&path).unwrap()
+    }
+
+    fn attempt(time: u64, answer: i32, outcome: Outcome) -> Attempt {
+        Attempt {
+            time,
+            day: 6,
+            part: 1,
+            answer: Answer::from(answer),
+            outcome,
+        }
+    }
+
+    #[test]
+    fn test_outcome_from_response() {
+        let test_cases = [
+            TestCase {
+                input: "<main><article><p>That's the right answer! You are one gold star closer.</p></article></main>",
+                expected: Ok(Outcome::Right),
+            },
+            TestCase {
+                input: "<article><p>That's not the right answer.  If you're stuck, make sure you're using the full input data.</p></article>",
+                expected: Ok(Outcome::Wrong),
+            },
+            TestCase {
+                input: "<article><p>That's not the right answer; your answer is too high.  Please wait one minute before trying again.</p></article>",
+                expected: Ok(Outcome::TooHigh),
+            },
+            TestCase {
+                input: "<article><p>That's not the right answer; your answer is too low.</p></article>",
+                expected: Ok(Outcome::TooLow),
+            },
+            TestCase {
+                input: "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.</p></article>",
+                expected: Ok(Outcome::Wait(65)),
+            },
+            TestCase {
+                input: "<article><p>You gave an answer too recently.  You have 38s left to wait.</p></article>",
+                expected: Ok(Outcome::Wait(38)),
+            },
+            TestCase {
+                input: "<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>",
+                expected: Ok(Outcome::AlreadySolved),
+            },
+            TestCase {
+                input: "<article>You gave an answer too recently.  You have 5é left to wait.</article>",
+                expected: Err(String::from(
+                    "could not read the time left to wait: You gave an answer too recently.  You have 5é left to wait.",
+                )),
+            },
+            TestCase {
+                input: "<article>Something else</article>",
+                expected: Err(String::from(
+                    "could not understand the response: Something else",
+                )),
+            },
+        ];
+        for TestCase { input, expected } in test_cases {
+            assert_eq!(expected, Outcome::from_response(input));
+        }
+    }
+
+    #[test]
+    fn test_outcome_round_trip() {
+        for outcome in [
+            Outcome::Right,
+            Outcome::Wrong,
+            Outcome::TooHigh,
+            Outcome::TooLow,
+            Outcome::Wait(42),
+            Outcome::AlreadySolved,
+        ] {
+            assert_eq!(Ok(outcome.clone()), outcome.to_string().parse());
+        }
+        assert!("wait".parse::<Outcome>().is_err());
+    }
+
+    #[test]
+    fn test_log_round_trip() {
+        let mut log = get_test_log("log");
+        log.append(attempt(100, 300, Outcome::TooHigh)).unwrap();
+        log.append(Attempt {
+            answer: Answer::from("ABC"),
+            ..attempt(200, 0, Outcome::Wait(30))
+        })
+        .unwrap();
+
+        log.append(Attempt {
+            answer: Answer::from("123"),
+            ..attempt(300, 0, Outcome::Wrong)
+        })
+        .unwrap();
+        log.append(Attempt {
+            answer: Answer::from("a\tb\nc"),
+            ..attempt(400, 0, Outcome::Wrong)
+        })
+        .unwrap();
+
+        let loaded = SubmissionLog::load(&log.path).unwrap();
+        assert_eq!(log.attempts, loaded.attempts);
+        assert_eq!(
+            "100\t6\t1\t300\ttoo high\n\
+             200\t6\t1\t\"ABC\"\twait 30s\n\
+             300\t6\t1\t\"123\"\twrong\n\
+             400\t6\t1\t\"a\\tb\\nc\"\twrong\n",
+            fs::read_to_string(&log.path).unwrap()
+        );
+        // the text answer isn't mistaken for the number
+        assert_eq!(
+            Err(String::from(
+                "123 was already submitted for day 6 part 1 and was wrong"
+            )),
+            loaded.check(6, 1, &Answer::from("123"), 500)
+        );
+        assert_eq!(Ok(()), loaded.check(6, 1, &Answer::from(123), 500));
+        fs::remove_file(&log.path).unwrap();
+
+        assert_eq!(
+            Err(String::from("line 2: expected 5 fields")),
+            parse_log("100\t6\t1\t300\ttoo high\n100\t6\t1\n")
+        );
+        assert_eq!(
+            Err(String::from("line 1: x is not a number")),
+            parse_log("x\t6\t1\t300\ttoo high\n")
+        );
+        assert_eq!(
+            Err(String::from("line 1: \\x is not an escape")),
+            parse_log("100\t6\t1\t\"\\x\"\ttoo high\n")
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let log = SubmissionLog {
+            path: PathBuf::new(),
+            attempts: vec![
+                attempt(100, 300, Outcome::TooHigh),
+                attempt(200, 100, Outcome::TooLow),
+                attempt(300, 250, Outcome::Wrong),
+            ],
+        };
+        let check = |answer: i32, now| log.check(6, 1, &Answer::from(answer), now);
+
+        assert_eq!(Ok(()), check(200, 400));
+        assert_eq!(
+            Err(String::from(
+                "250 was already submitted for day 6 part 1 and was wrong"
+            )),
+            check(250, 400)
+        );
+        assert_eq!(
+            Err(String::from(
+                "300 was already submitted for day 6 part 1 and was too high"
+            )),
+            check(300, 400)
+        );
+        assert_eq!(
+            Err(String::from("301 is too high: 300 already was")),
+            check(301, 400)
+        );
+        assert_eq!(
+            Err(String::from("99 is too low: 100 already was")),
+            check(99, 400)
+        );
+        assert_eq!(
+            Err(String::from("wait 20s before submitting another answer")),
+            check(200, 340)
+        );
+        // other parts aren't affected by these answers, only by the wait
+        assert_eq!(Ok(()), log.check(6, 2, &Answer::from(300), 400));
+    }
+
+    #[test]
+    fn test_check_after_wait_and_right() {
+        let mut log = SubmissionLog {
+            path: PathBuf::new(),
+            attempts: vec![attempt(100, 200, Outcome::Wait(90))],
+        };
+        assert_eq!(
+            Err(String::from("wait 1s before submitting another answer")),
+            log.check(6, 1, &Answer::from(200), 189)
+        );
+        assert_eq!(Ok(()), log.check(6, 1, &Answer::from(200), 190));
+
+        log.attempts.push(attempt(200, 288, Outcome::Right));
+        assert_eq!(
+            Err(String::from("day 6 part 1 was already solved with 288")),
+            log.check(6, 1, &Answer::from(288), 500)
+        );
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let server =
+            StubServer::start(vec![
+            (200, "<article><p>That's not the right answer; your answer is too low.</p></article>"),
+            (200, "<article><p>That's the right answer!</p></article>"),
+        ]);
+        let client = Client::new(&Config {
+            session: Some(String::from("abc123")),
+            base_url: server.base_url.clone(),
+            ..Config::default()
+        })
+        .unwrap();
+        let mut log = get_test_log("submit");
+
+        assert_eq!(
+            Ok(Outcome::TooLow),
+            submit_answer(&client, 2023, &mut log, 6, 1, &Answer::from(100), 1000)
+        );
+        // refused without asking the site
+        assert_eq!(
+            Err(String::from("wait 30s before submitting another answer")),
+            submit_answer(&client, 2023, &mut log, 6, 1, &Answer::from(288), 1030)
+        );
+        assert_eq!(
+            Ok(Outcome::Right),
+            submit_answer(&client, 2023, &mut log, 6, 1, &Answer::from(288), 1060)
+        );
+
+        let requests = server.requests();
+        assert_eq!(2, requests.len());
+        assert!(requests[0].starts_with("POST /2023/day/6/answer HTTP/1.1\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=1&answer=100"));
+        assert!(requests[1].ends_with("\r\n\r\nlevel=1&answer=288"));
+        assert_eq!(
+            "1000\t6\t1\t100\ttoo low\n1060\t6\t1\t288\tright\n",
+            fs::read_to_string(&log.path).unwrap()
+        );
+        fs::remove_file(&log.path).unwrap();
+    }
+}
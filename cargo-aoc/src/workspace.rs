@@ -0,0 +1,174 @@
+use aoc_support::input::INPUT_DIR_ENV;
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+/// The cargo workspace that holds the dayNN crates
+pub struct Workspace {
+    pub root: PathBuf,
+    pub days: Vec<String>,
+}
+
+impl Workspace {
+    /// Walk up from `start` until we find the Cargo.toml that declares the
+    /// workspace, then read the day crates out of its members list.
+    pub fn find(start: &Path) -> Result<Self, String> {
+        for dir in start.ancestors() {
+            let manifest_path = dir.join("Cargo.toml");
+            if let Ok(manifest) = fs::read_to_string(&manifest_path) {
+                if manifest.contains("[workspace]") {
+                    return Ok(Self {
+                        root: dir.to_path_buf(),
+                        days: get_day_members(&manifest),
+                    });
+                }
+            }
+        }
+
+        Err(format!(
+            "could not find a workspace Cargo.toml above {}",
+            start.display()
+        ))
+    }
+
+    /// Map a day given on the command line (e.g., "1" or "01") to the name
+    /// of its crate, provided that crate is a workspace member.
+    pub fn get_day_crate(&self, day: &str) -> Result<String, String> {
+        let day_crate = get_day_crate_name(day)?;
+        if self.days.contains(&day_crate) {
+            Ok(day_crate)
+        } else {
+            Err(format!("{} is not a member of the workspace", day_crate))
+        }
+    }
+
+    /// The directory the days read their puzzle inputs from: the one named
+    /// by `AOC_INPUT_DIR`, or the workspace's `inputs` directory
+    pub fn input_dir(&self) -> PathBuf {
+        env::var(INPUT_DIR_ENV)
+            .map(PathBuf::from)
+            .unwrap_or_else(|_| self.root.join("inputs"))
+    }
+}
+
+/// Convert a day number (with or without a leading zero) to its crate name
+pub fn get_day_crate_name(day: &str) -> Result<String, String> {
+    get_day_number(day).map(|day_number| format!("day{:02}", day_number))
+}
+
+/// Parse a day number given on the command line, e.g. "1" or "01"
+pub fn get_day_number(day: &str) -> Result<u32, String> {
+    match day.parse::<u32>() {
+        Ok(day_number) if (1..=25).contains(&day_number) => Ok(day_number),
+        _ => Err(format!("{} is not a day between 1 and 25", day)),
+    }
+}
+
+/// Return the entries of the workspace `members` list
+pub fn get_members(manifest: &str) -> Vec<String> {
+    let Some(members_start) = manifest.find("members") else {
+        return vec![];
+    };
+    let members = &manifest[members_start..];
+    let (Some(list_start), Some(list_end)) = (members.find('['), members.find(']')) else {
+        return vec![];
+    };
+
+    members[list_start + 1..list_end]
+        .split(',')
+        .map(|m| m.trim().trim_matches('"'))
+        .filter(|m| !m.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Return the workspace members that are dayNN crates
+fn get_day_members(manifest: &str) -> Vec<String> {
+    get_members(manifest)
+        .into_iter()
+        .filter(|m| is_day_crate_name(m))
+        .collect()
+}
+
+fn is_day_crate_name(name: &str) -> bool {
+    name.len() == 5 && name.starts_with("day") && name[3..].chars().all(|c| c.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    const TEST_MANIFEST: &str = r#"[workspace]
+resolver = "2"
+
+members = [
+  "cargo-aoc",
+  "day01",
+  "day02",
+  "day17",
+  "test_support",
+]
+"#;
+
+    use super::*;
+    use test_support::test_support::TestCase;
+
+    #[test]
+    fn test_get_members() {
+        assert_eq!(
+            vec!["cargo-aoc", "day01", "day02", "day17", "test_support"],
+            get_members(TEST_MANIFEST)
+        );
+        assert_eq!(vec!["day01"], get_members(r#"members = ["day01"]"#));
+        assert!(get_members("[package]").is_empty());
+    }
+
+    #[test]
+    fn test_get_day_members() {
+        assert_eq!(
+            vec!["day01", "day02", "day17"],
+            get_day_members(TEST_MANIFEST)
+        );
+    }
+
+    #[test]
+    fn test_get_day_crate_name() {
+        let test_cases = [
+            TestCase {
+                input: "1",
+                expected: Ok(String::from("day01")),
+            },
+            TestCase {
+                input: "01",
+                expected: Ok(String::from("day01")),
+            },
+            TestCase {
+                input: "17",
+                expected: Ok(String::from("day17")),
+            },
+            TestCase {
+                input: "26",
+                expected: Err(String::from("26 is not a day between 1 and 25")),
+            },
+            TestCase {
+                input: "abc",
+                expected: Err(String::from("abc is not a day between 1 and 25")),
+            },
+        ];
+        for TestCase { input, expected } in test_cases.iter() {
+            assert_eq!(get_day_crate_name(input), *expected);
+        }
+    }
+
+    #[test]
+    fn test_get_day_crate() {
+        let workspace = Workspace {
+            root: PathBuf::new(),
+            days: get_day_members(TEST_MANIFEST),
+        };
+        assert_eq!(Ok(String::from("day17")), workspace.get_day_crate("17"));
+        assert_eq!(
+            Err(String::from("day03 is not a member of the workspace")),
+            workspace.get_day_crate("3")
+        );
+    }
+}
@@ -0,0 +1,30 @@
+use aoc_support::rng::Rng;
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// A calibration document of `size` lines. Each line mixes lowercase
+/// letters, digits and spelled-out digits, and has at least one digit so
+/// both parts can find a calibration value.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut document = String::new();
+    for _ in 0..size {
+        let mut line = String::new();
+        for _ in 0..rng.range(1..=8) {
+            match rng.range(0..=3) {
+                0 => line.push(char::from(b'0' + rng.range(1..=9u8))),
+                1 => line.push_str(rng.choose::<&str>(&WORDS)),
+                _ => line.extend((0..rng.range(1..=4)).map(|_| char::from(rng.range(b'a'..=b'z')))),
+            }
+        }
+        if !line.chars().any(|c| c.is_ascii_digit()) {
+            let index = rng.range(0..=line.len());
+            line.insert(index, char::from(b'0' + rng.range(1..=9u8)));
+        }
+        document.push_str(&line);
+        document.push('\n');
+    }
+    document
+}
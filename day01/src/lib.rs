@@ -0,0 +1,363 @@
+pub mod generator;
+
+use aoc_support::{
+    answer::Answer,
+    parse::ParseError,
+    solution::{Solution, SolveError},
+};
+use std::collections::BTreeMap;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<String>;
+
+    fn day(&self) -> u32 {
+        1
+    }
+
+    fn generate_input(&self, seed: u64, size: usize) -> Option<String> {
+        Some(generator::generate(seed, size))
+    }
+
+    /// Every line needs a digit, which may be spelled out, to have a
+    /// calibration value
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|line| {
+                if get_digits_part2(line).is_empty() {
+                    Err(ParseError::new(
+                        input,
+                        line,
+                        "a digit or a spelled out digit",
+                    ))
+                } else {
+                    Ok(String::from(line))
+                }
+            })
+            .collect()
+    }
+
+    fn part1(&self, lines: &Self::Input) -> Result<Answer, SolveError> {
+        // only digits that are spelled out can't be read until part 2, so
+        // part 2's example can't be solved here
+        sum_calibration_values(lines, get_digits, "digits that aren't spelled out")
+    }
+
+    fn part2(&self, lines: &Self::Input) -> Result<Answer, SolveError> {
+        sum_calibration_values(lines, get_digits_part2, "digits")
+    }
+}
+
+/// Add up the calibration values of the lines, reading their digits with
+/// `get_digits_fn`, or give the first line with no `digits` to read
+fn sum_calibration_values(
+    lines: &[String],
+    get_digits_fn: fn(&str) -> Vec<i32>,
+    digits: &str,
+) -> Result<Answer, SolveError> {
+    lines
+        .iter()
+        .enumerate()
+        .try_fold(0, |sum, (index, line)| {
+            compute_calibration_value(line, get_digits_fn)
+                .map(|value| sum + value)
+                .ok_or_else(|| SolveError::new(format!("line {} has no {}", index + 1, digits)))
+        })
+        .map(Answer::from)
+}
+
+/// Find and return the digits that exist in the supplied string
+fn get_digits(input: &str) -> Vec<i32> {
+    input
+        .chars()
+        .filter(|c| c.is_ascii_digit())
+        .map(|c| c.to_digit(10).unwrap() as i32)
+        .collect()
+}
+
+struct Digit {
+    word: &'static str,
+    value: i32,
+}
+
+const DIGITS: [Digit; 9] = [
+    Digit {
+        word: "one",
+        value: 1,
+    },
+    Digit {
+        word: "two",
+        value: 2,
+    },
+    Digit {
+        word: "three",
+        value: 3,
+    },
+    Digit {
+        word: "four",
+        value: 4,
+    },
+    Digit {
+        word: "five",
+        value: 5,
+    },
+    Digit {
+        word: "six",
+        value: 6,
+    },
+    Digit {
+        word: "seven",
+        value: 7,
+    },
+    Digit {
+        word: "eight",
+        value: 8,
+    },
+    Digit {
+        word: "nine",
+        value: 9,
+    },
+];
+
+/// Find and return the digits that exist in the supplied string.
+/// Digits can be the numbers 1..=9 or their equivalent as
+/// English words.
+fn get_digits_part2(input: &str) -> Vec<i32> {
+    // create a map from indices to the digit at that index
+    let mut digits_by_index: BTreeMap<usize, i32> = BTreeMap::new();
+
+    for Digit { word, value } in DIGITS {
+        let word_indices = input.match_indices(word);
+        for (index, _) in word_indices {
+            digits_by_index.insert(index, value);
+        }
+        let number_indices = input.match_indices(std::char::from_digit(value as u32, 10).unwrap());
+        for (index, _) in number_indices {
+            digits_by_index.insert(index, value);
+        }
+    }
+
+    digits_by_index.values().copied().collect()
+}
+
+/// Compute the calibration value for the supplied text.
+/// Returns the two digit number combining the
+/// first and last digits found in the input per the
+/// get_digits function provided, or None if it finds none.
+fn compute_calibration_value(input: &str, get_digits_fn: fn(&str) -> Vec<i32>) -> Option<i32> {
+    let digits = get_digits_fn(input);
+    let first_digit = *digits.first()?;
+    let last_digit = *digits.last()?;
+    Some(first_digit * 10 + last_digit)
+}
+
+#[cfg(test)]
+mod tests {
+    const TEST_INPUT1: &str = r"1abc2
+    pqr3stu8vwx
+    a1b2c3d4e5f
+    treb7uchet";
+
+    const TEST_INPUT2: &str = r"two1nine
+    eightwothree
+    abcone2threexyz
+    xtwone3four
+    4nineeightseven2
+    zoneight234
+    7pqrstsixteen";
+
+    use super::*;
+    use test_support::{
+        generated::check_generated_inputs,
+        test_cases,
+        test_support::{dedent, TestCase},
+    };
+
+    #[test]
+    fn test_part1() {
+        let test_cases = [TestCase {
+            input: TEST_INPUT1,
+            expected: 142,
+        }];
+        for TestCase { input, expected } in test_cases.iter() {
+            assert_eq!(
+                Day01.part1(&Day01.parse(&dedent(input)).unwrap()).unwrap(),
+                *expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_part1_spelled_out_digits() {
+        let lines = Day01.parse(&dedent(TEST_INPUT2)).unwrap();
+        assert_eq!(
+            Err(SolveError::new(
+                "line 2 has no digits that aren't spelled out"
+            )),
+            Day01.part1(&lines)
+        );
+        assert_eq!(Ok(Answer::from(281)), Day01.part2(&lines));
+    }
+
+    #[test]
+    fn test_part2() {
+        let test_cases = [TestCase {
+            input: TEST_INPUT2,
+            expected: 281,
+        }];
+        for TestCase { input, expected } in test_cases.iter() {
+            assert_eq!(
+                Day01.part2(&Day01.parse(&dedent(input)).unwrap()).unwrap(),
+                *expected
+            );
+        }
+    }
+
+    test_cases! {
+        test_get_digits => get_digits,
+        first_and_last: TestCase {
+            input: "1abc2",
+            expected: vec![1, 2],
+        },
+        in_the_middle: TestCase {
+            input: "pqr3stu8vwx",
+            expected: vec![3, 8],
+        },
+        many_digits: TestCase {
+            input: "a1b2c3d4e5f",
+            expected: vec![1, 2, 3, 4, 5],
+        },
+        one_digit: TestCase {
+            input: "treb7uchet",
+            expected: vec![7],
+        },
+    }
+
+    test_cases! {
+        test_get_digits_part2 => get_digits_part2,
+        words_and_digit: TestCase {
+            input: "two1nine",
+            expected: vec![2, 1, 9],
+        },
+        overlapping_words: TestCase {
+            input: "eightwothree",
+            expected: vec![8, 2, 3],
+        },
+        words_in_noise: TestCase {
+            input: "abcone2threexyz",
+            expected: vec![1, 2, 3],
+        },
+        overlapping_word_first: TestCase {
+            input: "xtwone3four",
+            expected: vec![2, 1, 3, 4],
+        },
+        digits_at_the_ends: TestCase {
+            input: "4nineeightseven2",
+            expected: vec![4, 9, 8, 7, 2],
+        },
+        overlapping_word_then_digits: TestCase {
+            input: "zoneight234",
+            expected: vec![1, 8, 2, 3, 4],
+        },
+        teen_is_not_a_digit: TestCase {
+            input: "7pqrstsixteen",
+            expected: vec![7, 6],
+        },
+    }
+
+    #[test]
+    fn test_compute_calibration_value() {
+        let test_cases = [
+            TestCase {
+                input: "1abc2",
+                expected: 12,
+            },
+            TestCase {
+                input: "pqr3stu8vwx",
+                expected: 38,
+            },
+            TestCase {
+                input: "a1b2c3d4e5f",
+                expected: 15,
+            },
+            TestCase {
+                input: "treb7uchet",
+                expected: 77,
+            },
+        ];
+        for TestCase { input, expected } in test_cases.iter() {
+            assert_eq!(
+                compute_calibration_value(input, get_digits),
+                Some(*expected)
+            );
+        }
+    }
+    #[test]
+    fn test_compute_calibration_value_part2() {
+        let test_cases = [
+            TestCase {
+                input: "two1nine",
+                expected: 29,
+            },
+            TestCase {
+                input: "eightwothree",
+                expected: 83,
+            },
+            TestCase {
+                input: "abcone2threexyz",
+                expected: 13,
+            },
+            TestCase {
+                input: "xtwone3four",
+                expected: 24,
+            },
+            TestCase {
+                input: "4nineeightseven2",
+                expected: 42,
+            },
+            TestCase {
+                input: "zoneight234",
+                expected: 14,
+            },
+            TestCase {
+                input: "7pqrstsixteen",
+                expected: 76,
+            },
+        ];
+        for TestCase { input, expected } in test_cases.iter() {
+            assert_eq!(
+                compute_calibration_value(input, get_digits_part2),
+                Some(*expected)
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "line 2, column 1: expected a digit or a spelled out digit, found \"pqrstuvwx\"",
+            Day01
+                .parse("1abc2\npqrstuvwx\ntreb7uchet")
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            "line 2, column 1: expected a digit or a spelled out digit, found nothing",
+            Day01.parse("1abc2\n\n7").unwrap_err().to_string()
+        );
+        assert_eq!(None, compute_calibration_value("eightwothree", get_digits));
+    }
+
+    #[test]
+    fn test_generated_input() {
+        check_generated_inputs(&Day01, 100, |lines, part1, part2| {
+            assert_eq!(100, lines.len());
+            // every calibration value is a two digit number without zeros
+            for answer in [part1, part2] {
+                assert!((1100..=9900).contains(&i32::try_from(&answer).unwrap()));
+            }
+        });
+    }
+}
@@ -0,0 +1,27 @@
+use aoc_support::rng::Rng;
+
+const COLOURS: [&str; 3] = ["red", "green", "blue"];
+
+/// A record of `size` games, each with one to six hands of up to 20 cubes
+/// of each colour, so some games are possible with the part 1 bag and
+/// some are not
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut record = String::new();
+    for id in 1..=size {
+        let hands: Vec<String> = (0..rng.range(1..=6))
+            .map(|_| {
+                let mut colours = COLOURS.to_vec();
+                rng.shuffle(&mut colours);
+                colours.truncate(rng.range(1..=3));
+                colours
+                    .iter()
+                    .map(|colour| format!("{} {}", rng.range(1..=20), colour))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect();
+        record.push_str(&format!("Game {}: {}\n", id, hands.join("; ")));
+    }
+    record
+}
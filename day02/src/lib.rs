@@ -0,0 +1,440 @@
+pub mod generator;
+
+use aoc_support::{
+    answer::Answer,
+    combinators::{
+        labelled, lines, number, parse_all, separated, space1, tag, take_while1, Input, ParseResult,
+    },
+    parse::ParseError,
+    solution::{Solution, SolveError},
+};
+use std::cmp::max;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+
+    fn day(&self) -> u32 {
+        2
+    }
+
+    fn generate_input(&self, seed: u64, size: usize) -> Option<String> {
+        Some(generator::generate(seed, size))
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_all(lines(game), input)
+    }
+
+    fn part1(&self, games: &Self::Input) -> Result<Answer, SolveError> {
+        let game_bag = GameBag::new(12, 13, 14);
+        Ok(games
+            .iter()
+            .filter(|g| game_bag.is_game_possible(g))
+            .map(|g| u64::from(g.id))
+            .sum::<u64>()
+            .into())
+    }
+
+    fn part2(&self, games: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(games
+            .iter()
+            .map(|g| g.get_minimum_game_bag())
+            .map(|gb| gb.power())
+            .sum::<u128>()
+            .into())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct GameBag {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+impl GameBag {
+    fn new(red: u32, green: u32, blue: u32) -> Self {
+        Self { red, green, blue }
+    }
+
+    fn is_hand_possible(&self, hand: &Hand) -> bool {
+        let mut is_hand_possible = true;
+
+        if let Some(red) = hand.red {
+            is_hand_possible = is_hand_possible && (red <= self.red);
+        }
+
+        if let Some(green) = hand.green {
+            is_hand_possible = is_hand_possible && (green <= self.green);
+        }
+
+        if let Some(blue) = hand.blue {
+            is_hand_possible = is_hand_possible && (blue <= self.blue);
+        }
+
+        is_hand_possible
+    }
+
+    fn is_game_possible(&self, game: &Game) -> bool {
+        game.hands.iter().all(|h| self.is_hand_possible(h))
+    }
+
+    /// The product of the numbers of each colour, which for three u32s
+    /// always fits in a u128
+    fn power(&self) -> u128 {
+        u128::from(self.red) * u128::from(self.green) * u128::from(self.blue)
+    }
+}
+
+#[derive(Default, Debug, PartialEq)]
+struct Hand {
+    red: Option<u32>,
+    green: Option<u32>,
+    blue: Option<u32>,
+}
+
+impl Hand {
+    fn set_red(mut self, red: u32) -> Self {
+        self.red = Some(red);
+        self
+    }
+
+    fn set_green(mut self, green: u32) -> Self {
+        self.green = Some(green);
+        self
+    }
+
+    fn set_blue(mut self, blue: u32) -> Self {
+        self.blue = Some(blue);
+        self
+    }
+}
+
+impl TryFrom<&str> for Hand {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        parse_all(hand, value)
+    }
+}
+
+/// A hand such as `3 blue, 4 red`
+fn hand(input: Input<'_>) -> ParseResult<'_, Hand> {
+    let (cubes, input) = separated(", ", cube)(input)?;
+    let hand = cubes
+        .into_iter()
+        .fold(Hand::default(), |hand, (set_colour, number)| {
+            set_colour(hand, number)
+        });
+    Ok((hand, input))
+}
+
+/// The `Hand` method that sets the number of cubes of one colour
+type SetColour = fn(Hand, u32) -> Hand;
+
+/// A number of cubes of one colour, such as `3 blue`, as the setter of that
+/// colour and the number
+fn cube(input: Input<'_>) -> ParseResult<'_, (SetColour, u32)> {
+    let (number, input) = number(input)?;
+    let (_, input) = tag(" ")(input)?;
+    let (colour, rest) = take_while1(char::is_alphabetic, "a colour")(input)?;
+    let set_colour = match colour {
+        "red" => Hand::set_red,
+        "green" => Hand::set_green,
+        "blue" => Hand::set_blue,
+        _ => return Err(input.error(colour, "red, green or blue")),
+    };
+    Ok(((set_colour, number), rest))
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Game {
+    id: u32,
+    hands: Vec<Hand>,
+}
+
+impl Game {
+    fn new(id: u32) -> Self {
+        Self { id, hands: vec![] }
+    }
+
+    fn add_hand(mut self, hand: Hand) -> Self {
+        self.hands.push(hand);
+        self
+    }
+
+    fn get_minimum_game_bag(&self) -> GameBag {
+        let mut minimum_red = 0;
+        let mut minimum_green = 0;
+        let mut minimum_blue = 0;
+
+        for hand in self.hands.iter() {
+            if let Some(red) = hand.red {
+                minimum_red = max(minimum_red, red);
+            }
+
+            if let Some(green) = hand.green {
+                minimum_green = max(minimum_green, green);
+            }
+
+            if let Some(blue) = hand.blue {
+                minimum_blue = max(minimum_blue, blue)
+            }
+        }
+
+        GameBag::new(minimum_red, minimum_green, minimum_blue)
+    }
+}
+
+impl TryFrom<&str> for Game {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        parse_all(game, value)
+    }
+}
+
+/// A game such as `Game 1: 3 blue, 4 red; 2 green`
+fn game(input: Input<'_>) -> ParseResult<'_, Game> {
+    let (id, input) = labelled("Game", number)(input)?;
+    let (_, input) = space1(input)?;
+    let (hands, input) = separated("; ", hand)(input)?;
+    Ok((hands.into_iter().fold(Game::new(id), Game::add_hand), input))
+}
+
+#[cfg(test)]
+mod tests {
+    const TEST_INPUT: &str = r"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+    Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+    Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+    Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+    Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    use super::*;
+    use test_support::{
+        generated::check_generated_inputs,
+        test_support::{dedent, run_tests, TestCase},
+    };
+
+    #[test]
+    fn test_is_hand_possible() {
+        let game_bag = GameBag::new(12, 13, 14);
+        let test_cases = [
+            TestCase {
+                input: Hand::default().set_blue(3).set_red(4),
+                expected: true,
+            },
+            TestCase {
+                input: Hand::default().set_green(8).set_blue(6).set_red(20),
+                expected: false,
+            },
+            TestCase {
+                input: Hand::default().set_green(2),
+                expected: true,
+            },
+            TestCase {
+                input: Hand::default().set_blue(5).set_red(4).set_green(13),
+                expected: true,
+            },
+            TestCase {
+                input: Hand::default().set_green(3).set_blue(15).set_red(14),
+                expected: false,
+            },
+            TestCase {
+                input: Hand::default(),
+                expected: true,
+            },
+            TestCase {
+                input: Hand::default()
+                    .set_red(game_bag.red)
+                    .set_green(game_bag.green)
+                    .set_blue(game_bag.blue),
+                expected: true,
+            },
+        ];
+        run_tests(|hand| game_bag.is_hand_possible(hand), &test_cases);
+    }
+
+    #[test]
+    fn test_is_game_possible() {
+        let game_bag = GameBag::new(12, 13, 14);
+        assert!(game_bag.is_game_possible(
+            &Game::new(1)
+                .add_hand(Hand::default().set_blue(3).set_red(4))
+                .add_hand(Hand::default().set_red(1).set_green(2).set_blue(6))
+                .add_hand(Hand::default().set_green(2))
+        ));
+        assert!(game_bag.is_game_possible(
+            &Game::new(2)
+                .add_hand(Hand::default().set_blue(1).set_green(2))
+                .add_hand(Hand::default().set_green(3).set_blue(4).set_red(1))
+                .add_hand(Hand::default().set_green(1).set_blue(1))
+        ));
+        assert!(!game_bag.is_game_possible(
+            &Game::new(3)
+                .add_hand(Hand::default().set_green(8).set_blue(6).set_red(20))
+                .add_hand(Hand::default().set_blue(5).set_red(4).set_green(13))
+                .add_hand(Hand::default().set_green(5).set_red(1))
+        ));
+        assert!(!game_bag.is_game_possible(
+            &Game::new(4)
+                .add_hand(Hand::default().set_green(1).set_red(3).set_blue(6))
+                .add_hand(Hand::default().set_green(3).set_red(6))
+                .add_hand(Hand::default().set_green(3).set_blue(15).set_red(14))
+        ));
+        assert!(game_bag.is_game_possible(
+            &Game::new(5)
+                .add_hand(Hand::default().set_red(6).set_blue(1).set_green(3))
+                .add_hand(Hand::default().set_blue(2).set_red(1).set_green(2))
+        ));
+    }
+
+    #[test]
+    fn test_parse_hand() {
+        let test_cases = [
+            TestCase {
+                input: "3 blue, 4 red",
+                expected: Hand::default().set_blue(3).set_red(4),
+            },
+            TestCase {
+                input: "8 green, 6 blue, 20 red",
+                expected: Hand::default().set_green(8).set_blue(6).set_red(20),
+            },
+        ];
+        for TestCase { input, expected } in test_cases {
+            assert_eq!(Ok(expected), Hand::try_from(input));
+        }
+    }
+
+    #[test]
+    fn test_parse_game() {
+        let test_cases = [
+            TestCase {
+                input: "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green",
+                expected: Game::new(1)
+                    .add_hand(Hand::default().set_blue(3).set_red(4))
+                    .add_hand(Hand::default().set_red(1).set_green(2).set_blue(6))
+                    .add_hand(Hand::default().set_green(2)),
+            },
+            TestCase {
+                input: "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red",
+                expected: Game::new(3)
+                    .add_hand(Hand::default().set_green(8).set_blue(6).set_red(20))
+                    .add_hand(Hand::default().set_blue(5).set_red(4).set_green(13))
+                    .add_hand(Hand::default().set_green(5).set_red(1)),
+            },
+        ];
+        for TestCase { input, expected } in test_cases {
+            assert_eq!(Ok(expected), Game::try_from(input));
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let test_cases = [
+            TestCase {
+                input: "Game 1: 3 blue, 4 purple",
+                expected: "line 1, column 19: expected red, green or blue, found \"purple\"",
+            },
+            TestCase {
+                input: "Game x: 3 blue",
+                expected: "line 1, column 6: expected a number, found \"x\"",
+            },
+            TestCase {
+                input: "Game 1 3 blue",
+                expected: "line 1, column 7: expected \":\", found \" 3 blue\"",
+            },
+            TestCase {
+                input: "Game 1: 3 blue\nGame 2: 1 red; 2green",
+                expected: "line 2, column 16: expected a number, found \"2green\"",
+            },
+        ];
+        for TestCase { input, expected } in test_cases {
+            assert_eq!(expected, Day02.parse(input).unwrap_err().to_string());
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        let test_cases = [TestCase {
+            input: TEST_INPUT,
+            expected: 8,
+        }];
+        for TestCase { input, expected } in test_cases.iter() {
+            assert_eq!(
+                Day02.part1(&Day02.parse(&dedent(input)).unwrap()).unwrap(),
+                *expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_minimum_game_bag() {
+        let test_cases = [
+            TestCase {
+                input: "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green",
+                expected: GameBag::new(4, 2, 6),
+            },
+            TestCase {
+                input: "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red",
+                expected: GameBag::new(20, 13, 6),
+            },
+        ];
+        for TestCase { input, expected } in test_cases {
+            assert_eq!(
+                expected,
+                Game::try_from(input).unwrap().get_minimum_game_bag()
+            );
+        }
+    }
+
+    #[test]
+    fn test_game_bag_power() {
+        let test_cases = [
+            TestCase {
+                input: GameBag::new(4, 2, 6),
+                expected: 48,
+            },
+            TestCase {
+                input: GameBag::new(20, 13, 6),
+                expected: 1560,
+            },
+            // more than fits in a u32
+            TestCase {
+                input: GameBag::new(5000, 5000, 5000),
+                expected: 125_000_000_000,
+            },
+            TestCase {
+                input: GameBag::new(u32::MAX, u32::MAX, u32::MAX),
+                expected: u128::from(u32::MAX).pow(3),
+            },
+        ];
+        for TestCase { input, expected } in test_cases {
+            assert_eq!(expected, input.power());
+        }
+    }
+    #[test]
+    fn test_part2() {
+        let test_cases = [TestCase {
+            input: TEST_INPUT,
+            expected: 2286,
+        }];
+        for TestCase { input, expected } in test_cases.iter() {
+            assert_eq!(
+                Day02.part2(&Day02.parse(&dedent(input)).unwrap()).unwrap(),
+                *expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_generated_input() {
+        check_generated_inputs(&Day02, 100, |games, part1, part2| {
+            assert_eq!(100, games.len());
+            assert!(u64::try_from(&part1).unwrap() <= 5050);
+            assert!(u64::try_from(&part2).unwrap() >= 100);
+        });
+    }
+}
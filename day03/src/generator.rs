@@ -0,0 +1,39 @@
+use aoc_support::rng::Rng;
+
+const SYMBOLS: &[u8] = b"*#+$/@%=&-";
+
+/// An engine schematic `size` characters square, scattered with numbers of
+/// one to three digits and with symbols, about one in four of which are
+/// `*` so there are gears to find
+pub fn generate(seed: u64, size: usize) -> String {
+    let size = size.max(1);
+    let mut rng = Rng::new(seed);
+    let mut schematic = String::new();
+    for _ in 0..size {
+        let mut row = Vec::with_capacity(size);
+        while row.len() < size {
+            let space = size - row.len();
+            match rng.range(0..=9) {
+                0..=2 => {
+                    // a number is always followed by a '.' (or the end of
+                    // the row) so it doesn't run into the next one
+                    let digits = rng.range(1..=3).min(space);
+                    row.push(rng.range(b'1'..=b'9'));
+                    row.extend((1..digits).map(|_| rng.range(b'0'..=b'9')));
+                    if row.len() < size {
+                        row.push(b'.');
+                    }
+                }
+                3 => row.push(if rng.range(0..=3) == 0 {
+                    b'*'
+                } else {
+                    *rng.choose(SYMBOLS)
+                }),
+                _ => row.push(b'.'),
+            }
+        }
+        schematic.push_str(&String::from_utf8(row).unwrap());
+        schematic.push('\n');
+    }
+    schematic
+}
@@ -0,0 +1,491 @@
+pub mod generator;
+
+use aoc_support::{
+    answer::Answer,
+    parse::ParseError,
+    solution::{Solution, SolveError},
+};
+use grid::{Grid, Location};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Schematic;
+
+    fn day(&self) -> u32 {
+        3
+    }
+
+    fn generate_input(&self, seed: u64, size: usize) -> Option<String> {
+        Some(generator::generate(seed, size))
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Schematic::try_from(input)
+    }
+
+    fn part1(&self, schematic: &Self::Input) -> Result<Answer, SolveError> {
+        let mut sum = 0_u64;
+
+        for location in schematic.grid.locations() {
+            let symbol_at_location = schematic.get_symbol_at_location(&location);
+            if symbol_at_location == Symbol::Special || symbol_at_location == Symbol::Gear {
+                sum += schematic
+                    .get_part_numbers_adjacent_to_location(&location)
+                    .iter()
+                    .map(|&part_number| u64::from(part_number))
+                    .sum::<u64>();
+            }
+        }
+
+        Ok(Answer::from(sum))
+    }
+
+    fn part2(&self, schematic: &Self::Input) -> Result<Answer, SolveError> {
+        let mut sum = 0_u64;
+        for location in schematic.grid.locations() {
+            if schematic.is_gear(&location) {
+                let part_numbers = schematic.get_part_numbers_adjacent_to_location(&location);
+                // two u32s always multiply within a u64, but the sum may not
+                let gear_ratio = u64::from(part_numbers[0]) * u64::from(part_numbers[1]);
+                sum = sum.checked_add(gear_ratio).ok_or_else(|| {
+                    SolveError::new(format!(
+                        "the gear ratios up to line {} don't fit in a u64",
+                        location.row + 1
+                    ))
+                })?;
+            }
+        }
+
+        Ok(Answer::from(sum))
+    }
+}
+
+/// row[location] should contain an ASCII digit which may be part
+/// of a larger sequence of ASCII digits making up an integer number.
+/// This function finds and returns the integer containing the digit
+/// at row[location].
+fn get_integer_at_location(row: &[char], location: usize) -> u32 {
+    assert!(row[location].is_ascii_digit());
+
+    // need to walk backward from location until we hit start of row
+    // or we find a non-digit location
+    let mut number_start_index = location;
+    loop {
+        if !row[number_start_index].is_ascii_digit() {
+            // we've gone one step before the integer, step forward once
+            number_start_index += 1;
+            break;
+        }
+
+        if number_start_index == 0 {
+            // can't step back further, so break
+            break;
+        }
+
+        number_start_index -= 1;
+    }
+
+    // then walk forward from location until we hit end of row or
+    // we find a non-digit location
+    let mut number_end_index = location;
+    while number_end_index < row.len() {
+        if !row[number_end_index].is_ascii_digit() {
+            // we're passed the end of the integer, no need to
+            // step back because ranges are non-inclusive of their
+            // upper bound.
+            break;
+        }
+        number_end_index += 1;
+    }
+
+    row[number_start_index..number_end_index]
+        .iter()
+        .collect::<String>()
+        .parse::<u32>()
+        .expect("parsing checks that every part number fits in a u32")
+}
+
+#[derive(Debug, PartialEq)]
+enum Symbol {
+    Empty,
+    Special,
+    Digit,
+    Gear,
+}
+
+impl From<char> for Symbol {
+    fn from(value: char) -> Self {
+        if value.is_ascii_digit() {
+            return Symbol::Digit;
+        }
+
+        match value {
+            '.' => Symbol::Empty,
+            '*' => Symbol::Gear,
+            _ => Symbol::Special,
+        }
+    }
+}
+
+pub struct Schematic {
+    grid: Grid<char>,
+}
+
+impl TryFrom<&str> for Schematic {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        // the schematic is drawn in ASCII
+        let grid = Grid::parse(input, |c| {
+            if c.is_ascii() {
+                Ok(c)
+            } else {
+                Err("an ASCII character")
+            }
+        })?;
+        // the part numbers are read as u32s
+        for line in input.lines() {
+            let numbers = line.split(|c: char| !c.is_ascii_digit());
+            if let Some(number) = numbers
+                .filter(|n| !n.is_empty())
+                .find(|n| n.parse::<u32>().is_err())
+            {
+                return Err(ParseError::new(
+                    input,
+                    number,
+                    "a part number that fits in a u32",
+                ));
+            }
+        }
+        Ok(Schematic { grid })
+    }
+}
+
+impl Schematic {
+    fn get_symbol_at_location(&self, location: &Location) -> Symbol {
+        Symbol::from(self.grid[*location])
+    }
+
+    // returns a vector of part numbers (integers) that are at neighbouring locations
+    fn get_part_numbers_adjacent_to_location(&self, location: &Location) -> Vec<u32> {
+        let mut part_numbers: Vec<_> = vec![];
+        let neighbours: Vec<_> = self.grid.neighbours8(location).collect();
+
+        // in_digit guards against duplicating the same part number because the digits
+        // of the part number exist in multuple neighbouring locations. For two locations to
+        // be part of the same part number, they have to be adjacent (have a distance of one)
+        // and they have to both contain digits. This logic is captured below
+        let mut in_digit = false;
+
+        // keep track of the last location so we can test if two neighbours are adjacent
+        let mut last_location = None;
+        for (index, neighbour) in neighbours.iter().enumerate() {
+            if let Some(loc) = last_location {
+                if neighbour.manhattan_distance(loc) > 1 {
+                    in_digit = false;
+                }
+            }
+
+            last_location = Some(&neighbours[index]);
+            match self.get_symbol_at_location(neighbour) {
+                Symbol::Digit => {
+                    if !in_digit {
+                        // the first time we see a digit, we capture the part number
+                        // and then flag so we don't capture it again
+                        part_numbers.push(get_integer_at_location(
+                            self.grid.row(neighbour.row),
+                            neighbour.col,
+                        ));
+                        in_digit = true;
+                    }
+                }
+                _ => in_digit = false, // any non-digit breaks us out
+            }
+        }
+
+        part_numbers
+    }
+
+    // a location reprsents a gear if it contains a gear symbol and it has exactly
+    // two neighbouring part numbers
+    fn is_gear(&self, location: &Location) -> bool {
+        self.get_symbol_at_location(location) == Symbol::Gear
+            && self.get_part_numbers_adjacent_to_location(location).len() == 2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    const TEST_INPUT: &str = include_str!("../examples/example1.txt");
+
+    use super::*;
+    use aoc_support::examples::load_examples;
+    use test_support::{
+        generated::check_generated_inputs,
+        test_support::{run_tests, TestCase},
+    };
+
+    #[test]
+    fn test_get_integer_at_location() {
+        let row = |row: &str| row.chars().collect::<Vec<_>>();
+        let row1 = &row("467..114..");
+        assert_eq!(467, get_integer_at_location(row1, 0));
+        assert_eq!(467, get_integer_at_location(row1, 1));
+        assert_eq!(467, get_integer_at_location(row1, 2));
+        assert_eq!(114, get_integer_at_location(row1, 5));
+        assert_eq!(114, get_integer_at_location(row1, 6));
+        assert_eq!(114, get_integer_at_location(row1, 7));
+
+        let row2 = &row(".664.598..");
+        assert_eq!(664, get_integer_at_location(row2, 2));
+        assert_eq!(598, get_integer_at_location(row2, 7));
+
+        let row3 = &row(".......45");
+        assert_eq!(45, get_integer_at_location(row3, 7));
+        assert_eq!(45, get_integer_at_location(row3, 8));
+    }
+
+    #[test]
+    fn test_get_neighbours_of_location() {
+        let grid = Grid::filled(10, 10, '.');
+        let get_neighbours_of_location =
+            |location: &Location| grid.neighbours8(location).collect::<Vec<_>>();
+
+        // test upper left corner
+        let neighbours = get_neighbours_of_location(&Location::new(0, 0));
+        assert_eq!(3, neighbours.len());
+        assert!(neighbours.contains(&Location::new(0, 1)));
+        assert!(neighbours.contains(&Location::new(1, 0)));
+        assert!(neighbours.contains(&Location::new(1, 1)));
+
+        // test lower right corner
+        let neighbours = get_neighbours_of_location(&Location::new(9, 9));
+        assert_eq!(3, neighbours.len());
+        assert!(neighbours.contains(&Location::new(8, 9)));
+        assert!(neighbours.contains(&Location::new(9, 8)));
+        assert!(neighbours.contains(&Location::new(8, 8)));
+
+        // test upper right corner
+        let neighbours = get_neighbours_of_location(&Location::new(0, 9));
+        assert_eq!(3, neighbours.len());
+        assert!(neighbours.contains(&Location::new(0, 8)));
+        assert!(neighbours.contains(&Location::new(1, 9)));
+        assert!(neighbours.contains(&Location::new(1, 8)));
+
+        // test lower left corner
+        let neighbours = get_neighbours_of_location(&Location::new(9, 0));
+        assert_eq!(3, neighbours.len());
+        assert!(neighbours.contains(&Location::new(8, 0)));
+        assert!(neighbours.contains(&Location::new(9, 1)));
+        assert!(neighbours.contains(&Location::new(8, 1)));
+
+        // test left edge
+        let neighbours = get_neighbours_of_location(&Location::new(5, 0));
+        assert_eq!(5, neighbours.len());
+        assert!(neighbours.contains(&Location::new(4, 0)));
+        assert!(neighbours.contains(&Location::new(6, 0)));
+        assert!(neighbours.contains(&Location::new(4, 1)));
+        assert!(neighbours.contains(&Location::new(5, 1)));
+        assert!(neighbours.contains(&Location::new(6, 1)));
+
+        // test right edge
+        let neighbours = get_neighbours_of_location(&Location::new(5, 9));
+        assert_eq!(5, neighbours.len());
+        assert!(neighbours.contains(&Location::new(4, 9)));
+        assert!(neighbours.contains(&Location::new(6, 9)));
+        assert!(neighbours.contains(&Location::new(4, 8)));
+        assert!(neighbours.contains(&Location::new(5, 8)));
+        assert!(neighbours.contains(&Location::new(6, 8)));
+
+        // test top edge
+        let neighbours = get_neighbours_of_location(&Location::new(0, 5));
+        assert_eq!(5, neighbours.len());
+        assert!(neighbours.contains(&Location::new(0, 4)));
+        assert!(neighbours.contains(&Location::new(0, 6)));
+        assert!(neighbours.contains(&Location::new(1, 4)));
+        assert!(neighbours.contains(&Location::new(1, 5)));
+        assert!(neighbours.contains(&Location::new(1, 6)));
+
+        // test bottom edge
+        let neighbours = get_neighbours_of_location(&Location::new(9, 5));
+        assert_eq!(5, neighbours.len());
+        assert!(neighbours.contains(&Location::new(9, 4)));
+        assert!(neighbours.contains(&Location::new(9, 6)));
+        assert!(neighbours.contains(&Location::new(8, 4)));
+        assert!(neighbours.contains(&Location::new(8, 5)));
+        assert!(neighbours.contains(&Location::new(8, 6)));
+
+        // test a centre location
+        let neighbours = get_neighbours_of_location(&Location::new(5, 5));
+        assert_eq!(8, neighbours.len());
+        assert!(neighbours.contains(&Location::new(4, 4)));
+        assert!(neighbours.contains(&Location::new(4, 5)));
+        assert!(neighbours.contains(&Location::new(4, 6)));
+        assert!(neighbours.contains(&Location::new(5, 4)));
+        assert!(neighbours.contains(&Location::new(5, 6)));
+        assert!(neighbours.contains(&Location::new(6, 4)));
+        assert!(neighbours.contains(&Location::new(6, 5)));
+        assert!(neighbours.contains(&Location::new(6, 6)));
+    }
+
+    #[test]
+    fn test_get_symbol_at_schematic_location() {
+        let schematic = Schematic::try_from(TEST_INPUT).unwrap();
+        let test_cases = [
+            TestCase {
+                input: Location::new(0, 0),
+                expected: Symbol::Digit,
+            },
+            TestCase {
+                input: Location::new(0, 4),
+                expected: Symbol::Empty,
+            },
+            TestCase {
+                input: Location::new(1, 3),
+                expected: Symbol::Gear,
+            },
+            TestCase {
+                input: Location::new(3, 6),
+                expected: Symbol::Special,
+            },
+        ];
+        run_tests(
+            |location| schematic.get_symbol_at_location(location),
+            &test_cases,
+        );
+    }
+
+    #[test]
+    fn test_get_part_numbers_adjacent_to_location() {
+        let schematic = Schematic::try_from(TEST_INPUT).unwrap();
+        let part_numbers = schematic.get_part_numbers_adjacent_to_location(&Location::new(1, 3));
+        assert_eq!(2, part_numbers.len());
+        assert!(part_numbers.contains(&467));
+        assert!(part_numbers.contains(&35));
+
+        let schematic = Schematic::try_from(".....\n35.35\n..*..\n.....\n..*..").unwrap();
+        let part_numbers = schematic.get_part_numbers_adjacent_to_location(&Location::new(2, 2));
+        assert_eq!(2, part_numbers.len());
+        assert!(part_numbers.iter().all(|pn| *pn == 35));
+
+        let schematic = Schematic::try_from("...\n3*5\n...").unwrap();
+        let part_numbers = schematic.get_part_numbers_adjacent_to_location(&Location::new(1, 1));
+        assert_eq!(2, part_numbers.len());
+        assert!(part_numbers.contains(&3));
+        assert!(part_numbers.contains(&5));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let test_cases = [
+            TestCase {
+                input: "",
+                expected: "line 1, column 1: expected a row of the grid, found nothing",
+            },
+            TestCase {
+                input: "467..\n...*..\n..35.",
+                expected: "line 2, column 1: expected a row of 5 cells, found \"...*..\"",
+            },
+            TestCase {
+                input: "467..\n..£..",
+                expected: "line 2, column 3: expected an ASCII character, found \"£\"",
+            },
+            TestCase {
+                input: "467...........\n...*..........\n..99999999999*",
+                expected: "line 3, column 3: expected a part number that fits in a u32, \
+                found \"99999999999\"",
+            },
+        ];
+        for TestCase { input, expected } in test_cases {
+            assert_eq!(
+                expected,
+                Day03.parse(input).err().map(|e| e.to_string()).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        let mut checked = 0;
+        for example in load_examples(env!("CARGO_MANIFEST_DIR")) {
+            if let Some(expected) = example.expected(1) {
+                let answer = Day03.part1(&Day03.parse(&example.input).unwrap()).unwrap();
+                assert_eq!(*expected, answer, "{}", example.name);
+                checked += 1;
+            }
+        }
+        assert!(checked > 0, "no example has an answer to part 1");
+    }
+
+    #[test]
+    fn test_is_gear() {
+        let schematic = Schematic::try_from(TEST_INPUT).unwrap();
+        let test_cases = [
+            TestCase {
+                input: Location::new(1, 3),
+                expected: true,
+            },
+            TestCase {
+                input: Location::new(8, 5),
+                expected: true,
+            },
+            TestCase {
+                input: Location::new(4, 3),
+                expected: false,
+            },
+            TestCase {
+                input: Location::new(3, 6),
+                expected: false,
+            },
+        ];
+        run_tests(|location| schematic.is_gear(location), &test_cases);
+    }
+
+    #[test]
+    fn test_part2() {
+        let mut checked = 0;
+        for example in load_examples(env!("CARGO_MANIFEST_DIR")) {
+            if let Some(expected) = example.expected(2) {
+                let answer = Day03.part2(&Day03.parse(&example.input).unwrap()).unwrap();
+                assert_eq!(*expected, answer, "{}", example.name);
+                checked += 1;
+            }
+        }
+        assert!(checked > 0, "no example has an answer to part 2");
+    }
+
+    #[test]
+    fn test_gear_ratios_wider_than_u64() {
+        // each gear ratio fits in a u64, but not both of them
+        let gear = "4294967295*4294967295";
+        let schematic = Day03
+            .parse(&[gear, &".".repeat(gear.len()), gear].join("\n"))
+            .unwrap();
+        assert_eq!(
+            Err(SolveError::new(
+                "the gear ratios up to line 3 don't fit in a u64"
+            )),
+            Day03.part2(&schematic)
+        );
+    }
+
+    #[test]
+    fn test_gear_ratios_wider_than_u32() {
+        let schematic = Day03.parse("99999*99999").unwrap();
+        assert_eq!(Ok(Answer::from(199_998)), Day03.part1(&schematic));
+        assert_eq!(Ok(Answer::from(9_999_800_001_u64)), Day03.part2(&schematic));
+    }
+
+    #[test]
+    fn test_generated_input() {
+        check_generated_inputs(&Day03, 50, |schematic, part1, part2| {
+            assert_eq!(
+                (50, 50),
+                (schematic.grid.num_rows(), schematic.grid.num_cols())
+            );
+            assert!(u64::try_from(&part1).unwrap() > 0);
+            assert!(u64::try_from(&part2).unwrap() > 0);
+        });
+    }
+}
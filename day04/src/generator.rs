@@ -0,0 +1,45 @@
+use aoc_support::rng::Rng;
+
+const WINNING_NUMBERS: usize = 10;
+const SELECTED_NUMBERS: usize = 25;
+/// Cards only win copies of cards in the same block, so the number of
+/// copies in part 2 grows with the number of cards rather than
+/// exponentially
+const BLOCK: usize = 10;
+
+/// A pile of `size` scratchcards laid out like the puzzle's, with ten
+/// winning numbers and 25 selected numbers from 1 to 99. No card wins
+/// copies of cards past the end of the pile.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut pile = String::new();
+    for index in 0..size {
+        let block_end = ((index / BLOCK + 1) * BLOCK).min(size);
+        let matches = rng.range(0..=(block_end - index - 1).min(WINNING_NUMBERS));
+
+        let mut numbers: Vec<u32> = (1..=99).collect();
+        rng.shuffle(&mut numbers);
+        let winning = &numbers[..WINNING_NUMBERS];
+        let mut selected: Vec<u32> = winning[..matches]
+            .iter()
+            .chain(&numbers[WINNING_NUMBERS..WINNING_NUMBERS + SELECTED_NUMBERS - matches])
+            .copied()
+            .collect();
+        rng.shuffle(&mut selected);
+
+        let format_numbers = |numbers: &[u32]| {
+            numbers
+                .iter()
+                .map(|n| format!("{:>2}", n))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        pile.push_str(&format!(
+            "Card {:>3}: {} | {}\n",
+            index + 1,
+            format_numbers(winning),
+            format_numbers(&selected)
+        ));
+    }
+    pile
+}
@@ -0,0 +1,333 @@
+pub mod generator;
+
+use aoc_support::{
+    answer::Answer,
+    combinators::{before, labelled, lines, number, numbers, parse_all, Input, ParseResult},
+    parse::ParseError,
+    solution::{Solution, SolveError},
+};
+use std::collections::HashSet;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Card>;
+
+    fn day(&self) -> u32 {
+        4
+    }
+
+    fn generate_input(&self, seed: u64, size: usize) -> Option<String> {
+        Some(generator::generate(seed, size))
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let cards = parse_all(lines(card), input)?;
+        // part 2 finds the cards won by their position in the list, and no
+        // card can win copies of cards past the end of it
+        for (index, (card, line)) in cards.iter().zip(input.lines()).enumerate() {
+            if card.id as usize != index + 1 {
+                return Err(ParseError::new(input, line, format!("card {}", index + 1)));
+            }
+            let cards_after = cards.len() - index - 1;
+            if card.get_number_of_matches() > cards_after {
+                return Err(ParseError::new(
+                    input,
+                    line,
+                    format!(
+                        "at most {} matching numbers, one for each card after card {}",
+                        cards_after, card.id
+                    ),
+                ));
+            }
+        }
+        Ok(cards)
+    }
+
+    fn part1(&self, cards: &Self::Input) -> Result<Answer, SolveError> {
+        cards
+            .iter()
+            .try_fold(0_u64, |total, card| {
+                card.get_score()
+                    .and_then(|score| total.checked_add(score))
+                    .ok_or_else(|| {
+                        SolveError::new(format!(
+                            "the points up to card {} don't fit in a u64",
+                            card.id
+                        ))
+                    })
+            })
+            .map(Answer::from)
+    }
+
+    fn part2(&self, cards: &Self::Input) -> Result<Answer, SolveError> {
+        let mut card_counts = vec![1u64; cards.len()];
+        let too_many = |card: &Card| {
+            SolveError::new(format!(
+                "the number of cards up to card {} doesn't fit in a u64",
+                card.id
+            ))
+        };
+
+        let mut total = 0_u64;
+        for (index, card) in cards.iter().enumerate() {
+            // we get `count` copies of the next `score` number of cards
+            let score = card.get_number_of_matches();
+            let count = card_counts[index];
+            for index2 in 1..=score {
+                let id_of_new_card_copy = index + index2;
+                card_counts[id_of_new_card_copy] = card_counts[id_of_new_card_copy]
+                    .checked_add(count)
+                    .ok_or_else(|| too_many(card))?;
+            }
+            total = total.checked_add(count).ok_or_else(|| too_many(card))?;
+        }
+        Ok(total.into())
+    }
+}
+
+pub struct Card {
+    id: i32,
+    winning_numbers: HashSet<i32>,
+    selected_numbers: HashSet<i32>,
+}
+
+impl TryFrom<&str> for Card {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        parse_all(card, value)
+    }
+}
+
+/// A card such as `Card 1: 41 48 | 83 86 6`
+fn card(input: Input<'_>) -> ParseResult<'_, Card> {
+    let (id, input) = labelled("Card", number)(input)?;
+    let (winning_numbers, input) = before(" | ", numbers)(input)?;
+    let (selected_numbers, input) = numbers(input)?;
+    let card = Card {
+        id,
+        winning_numbers: HashSet::from_iter(winning_numbers),
+        selected_numbers: HashSet::from_iter(selected_numbers),
+    };
+    Ok((card, input))
+}
+
+impl Card {
+    /// The card's points, or `None` if they don't fit in a u64
+    fn get_score(&self) -> Option<u64> {
+        let number_of_matches = self.get_number_of_matches();
+
+        if number_of_matches == 0 {
+            Some(0)
+        } else {
+            2_u64.checked_pow(u32::try_from(number_of_matches - 1).ok()?)
+        }
+    }
+
+    fn get_number_of_matches(&self) -> usize {
+        self.winning_numbers
+            .intersection(&self.selected_numbers)
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    const TEST_INPUT: &str = r"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+    Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+    Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+    Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+    Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+    Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    use super::*;
+    use test_support::{
+        generated::check_generated_inputs,
+        property::{check, ints, vecs},
+        test_support::{dedent, TestCase},
+    };
+
+    #[test]
+    fn test_parse_card() {
+        let card = Card::try_from("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53").unwrap();
+        assert_eq!(1, card.id);
+        assert_eq!(5, card.winning_numbers.len());
+        assert!(card.winning_numbers.contains(&41));
+        assert!(card.winning_numbers.contains(&48));
+        assert!(card.winning_numbers.contains(&83));
+        assert!(card.winning_numbers.contains(&86));
+        assert!(card.winning_numbers.contains(&17));
+
+        assert_eq!(8, card.selected_numbers.len());
+        assert!(card.selected_numbers.contains(&83));
+        assert!(card.selected_numbers.contains(&86));
+        assert!(card.selected_numbers.contains(&6));
+        assert!(card.selected_numbers.contains(&31));
+        assert!(card.selected_numbers.contains(&17));
+        assert!(card.selected_numbers.contains(&9));
+        assert!(card.selected_numbers.contains(&48));
+        assert!(card.selected_numbers.contains(&53));
+    }
+
+    #[test]
+    fn test_card_score() {
+        let card = Card::try_from("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53").unwrap();
+        assert_eq!(Some(8), card.get_score());
+
+        let card = Card::try_from("Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19").unwrap();
+        assert_eq!(Some(2), card.get_score());
+
+        let card = Card::try_from("Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1").unwrap();
+        assert_eq!(Some(2), card.get_score());
+
+        let card = Card::try_from("Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83").unwrap();
+        assert_eq!(Some(1), card.get_score());
+
+        let card = Card::try_from("Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36").unwrap();
+        assert_eq!(Some(0), card.get_score());
+
+        let card = Card::try_from("Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11").unwrap();
+        assert_eq!(Some(0), card.get_score());
+    }
+
+    #[test]
+    fn test_score_doubles_with_each_match() {
+        let numbers = || vecs(ints(1..=30), 0..=10);
+        check(&(numbers(), numbers()), |(winning, selected)| {
+            let card = Card {
+                id: 1,
+                winning_numbers: winning.iter().copied().collect(),
+                selected_numbers: selected.iter().copied().collect(),
+            };
+            let matches = card
+                .selected_numbers
+                .iter()
+                .filter(|n| winning.contains(n))
+                .count();
+            let expected = if matches == 0 { 0 } else { 1 << (matches - 1) };
+            card.get_score() == Some(expected)
+        });
+    }
+
+    /// A card whose numbers 1 to `matches` are all winning numbers
+    fn card_with_matches(id: i32, matches: i32) -> Card {
+        Card {
+            id,
+            winning_numbers: (1..=matches).collect(),
+            selected_numbers: (1..=matches).collect(),
+        }
+    }
+
+    #[test]
+    fn test_points_wider_than_u32() {
+        assert_eq!(Some(1 << 32), card_with_matches(1, 33).get_score());
+        assert_eq!(Some(1 << 63), card_with_matches(1, 64).get_score());
+        assert_eq!(None, card_with_matches(1, 65).get_score());
+
+        let cards = vec![card_with_matches(1, 33), card_with_matches(2, 33)];
+        assert_eq!(Ok(Answer::from(1_u64 << 33)), Day04.part1(&cards));
+        let cards = vec![card_with_matches(1, 64), card_with_matches(2, 64)];
+        assert_eq!(
+            Err(SolveError::new(
+                "the points up to card 2 don't fit in a u64"
+            )),
+            Day04.part1(&cards)
+        );
+    }
+
+    #[test]
+    fn test_card_counts_wider_than_u64() {
+        // each card wins a copy of every card after it, so card n has
+        // 2^(n - 1) copies
+        let pile = |size| -> Vec<_> {
+            (1..=size)
+                .map(|id| card_with_matches(id, size - id))
+                .collect()
+        };
+        assert_eq!(Ok(Answer::from(u64::MAX)), Day04.part2(&pile(64)));
+        assert_eq!(
+            Err(SolveError::new(
+                "the number of cards up to card 64 doesn't fit in a u64"
+            )),
+            Day04.part2(&pile(65))
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let test_cases = [
+            TestCase {
+                input: "Card 1: 41 48 | 83 x6",
+                expected: "line 1, column 20: expected a number, found \"x6\"",
+            },
+            TestCase {
+                input: "Card 1: 41 48 | 83 86\nCard 2: 13 32 61 30",
+                expected: "line 2, column 8: expected \" | \", found \" 13 32 61 30\"",
+            },
+            TestCase {
+                input: "Card 1: 41 | 83\nCard 3: 41 | 83",
+                expected: "line 2, column 1: expected card 2, found \"Card 3: 41 | 83\"",
+            },
+            TestCase {
+                input: "Card one: 41 | 83",
+                expected: "line 1, column 6: expected a number, found \"one\"",
+            },
+            // part 2 would win a copy of a card that isn't in the pile
+            TestCase {
+                input: "Card 1: 41 | 41",
+                expected: "line 1, column 1: expected at most 0 matching numbers, one for each \
+                card after card 1, found \"Card 1: 41 | 41\"",
+            },
+            TestCase {
+                input: "Card 1: 41 48 | 41 48\nCard 2: 13 | 61\nCard 3: 7 | 7",
+                expected: "line 3, column 1: expected at most 0 matching numbers, one for each \
+                card after card 3, found \"Card 3: 7 | 7\"",
+            },
+        ];
+        for TestCase { input, expected } in test_cases {
+            assert_eq!(
+                expected,
+                Day04.parse(input).err().map(|e| e.to_string()).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        let test_cases = [TestCase {
+            input: TEST_INPUT,
+            expected: 13,
+        }];
+        for TestCase { input, expected } in test_cases.iter() {
+            assert_eq!(
+                Day04.part1(&Day04.parse(&dedent(input)).unwrap()).unwrap(),
+                *expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_part2() {
+        let test_cases = [TestCase {
+            input: TEST_INPUT,
+            expected: 30,
+        }];
+        for TestCase { input, expected } in test_cases.iter() {
+            assert_eq!(
+                Day04.part2(&Day04.parse(&dedent(input)).unwrap()).unwrap(),
+                *expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_generated_input() {
+        check_generated_inputs(&Day04, 100, |cards, _, part2| {
+            assert_eq!(100, cards.len());
+            // each card wins copies of at most the other nine in its block
+            let card_count = u64::try_from(&part2).unwrap();
+            assert!((100..=100 * 512).contains(&card_count));
+        });
+    }
+}
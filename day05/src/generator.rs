@@ -0,0 +1,56 @@
+use aoc_support::rng::Rng;
+
+const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+/// An almanac with `size` seed ranges of up to 100 seeds each, and maps
+/// with `size` ranges each. Each map's ranges cover the same span of
+/// values as sources and as destinations, in a shuffled order.
+pub fn generate(seed: u64, size: usize) -> String {
+    let size = size.max(1);
+    let mut rng = Rng::new(seed);
+    let lengths: Vec<Vec<i64>> = (1..CATEGORIES.len())
+        .map(|_| (0..size).map(|_| rng.range(1..=199)).collect())
+        .collect();
+    let span = lengths.iter().map(|l| l.iter().sum()).max().unwrap_or(0);
+
+    let seeds: Vec<String> = (0..size)
+        .map(|_| format!("{} {}", rng.range(0..=span), rng.range(1..=100)))
+        .collect();
+    let mut almanac = format!("seeds: {}\n", seeds.join(" "));
+
+    for (index, lengths) in lengths.iter().enumerate() {
+        almanac.push_str(&format!(
+            "\n{}-to-{} map:\n",
+            CATEGORIES[index],
+            CATEGORIES[index + 1]
+        ));
+
+        let mut destination_order: Vec<usize> = (0..lengths.len()).collect();
+        rng.shuffle(&mut destination_order);
+        let mut destinations = vec![0; lengths.len()];
+        let mut destination = 0;
+        for range in destination_order {
+            destinations[range] = destination;
+            destination += lengths[range];
+        }
+
+        let mut ranges = vec![];
+        let mut source = 0;
+        for (range, length) in lengths.iter().enumerate() {
+            ranges.push(format!("{} {} {}\n", destinations[range], source, length));
+            source += length;
+        }
+        rng.shuffle(&mut ranges);
+        almanac.push_str(&ranges.concat());
+    }
+    almanac
+}
@@ -0,0 +1,529 @@
+pub mod generator;
+
+use aoc_support::{
+    answer::Answer,
+    cancel,
+    combinators::{
+        header, line, lines, number, numbers, pair, parse_all, preceded, sections, space0, tag,
+        take_while1, Input, ParseResult, Parser,
+    },
+    parse::ParseError,
+    solution::{Solution, SolveError},
+};
+use intervals::{Interval, IntervalSet};
+use std::collections::HashMap;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = SeedAlmanac;
+
+    fn day(&self) -> u32 {
+        5
+    }
+
+    fn generate_input(&self, seed: u64, size: usize) -> Option<String> {
+        Some(generator::generate(seed, size))
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let seeds = line(preceded(header("seeds"), numbers));
+        let ((seeds_line, seeds), entries) = parse_all(
+            pair(with_line(seeds), sections(with_line(almanac_entry))),
+            input,
+        )?;
+        check_seed_ranges(input, seeds_line, &seeds)?;
+        Ok(SeedAlmanac {
+            seeds,
+            almanac: Almanac::from_entries(input, seeds_line, entries)?,
+        })
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer, SolveError> {
+        input
+            .get_lowest_location(&input.seeds)
+            .map(Answer::from)
+            .ok_or(SolveError::new("there are no seeds"))
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer, SolveError> {
+        input
+            .almanac
+            .map_set(&seed_ranges(&input.seeds))
+            .min()
+            .map(Answer::from)
+            .ok_or(SolveError::new("there are no seeds in the seed ranges"))
+    }
+}
+
+/// The seeds to be planted and the almanac for working out where to plant them
+pub struct SeedAlmanac {
+    seeds: Vec<i64>,
+    almanac: Almanac,
+}
+
+impl SeedAlmanac {
+    fn get_lowest_location(&self, seeds: &[i64]) -> Option<i64> {
+        seeds.iter().map(|v| self.almanac.map(*v)).min()
+    }
+}
+
+/// Check that the seed numbers on `seeds_line` can be read as pairs of range
+/// start and range length, as part 2 reads them. Each range needs a seed in
+/// it and has to end within an i64.
+fn check_seed_ranges(text: &str, seeds_line: &str, seeds: &[i64]) -> Result<(), ParseError> {
+    if seeds.is_empty() || seeds.len() % 2 == 1 {
+        return Err(ParseError::new(
+            text,
+            seeds_line,
+            "seed numbers in pairs of range start and length",
+        ));
+    }
+    // the numbers after `seeds:`, as slices of the text for errors to point at
+    let tokens = seeds_line.split_ascii_whitespace().skip(1);
+    for (pair, length) in seeds.chunks(2).zip(tokens.skip(1).step_by(2)) {
+        if pair[1] < 1 {
+            return Err(ParseError::new(
+                text,
+                length,
+                "a seed range length of at least 1",
+            ));
+        }
+        if pair[0].checked_add(pair[1]).is_none() {
+            return Err(ParseError::new(
+                text,
+                length,
+                "a seed range length that ends the range within an i64",
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Treat the seed numbers as pairs of range start and range length, and
+/// return the set of seeds in those ranges
+fn seed_ranges(seeds: &[i64]) -> IntervalSet {
+    seeds
+        .chunks(2)
+        .map(|pair| Interval::with_length(pair[0], pair[1]))
+        .collect()
+}
+
+#[derive(Debug, PartialEq)]
+enum DestinationValue {
+    In(i64),
+    Out(i64),
+}
+
+/// A range of source values, and how far the entry moves them to get their
+/// destination values
+#[derive(Debug)]
+struct AlmanacRange {
+    source: Interval,
+    offset: i64,
+}
+
+impl AlmanacRange {
+    fn new(destination_range_start: i64, source_range_start: i64, range_length: i64) -> Self {
+        Self {
+            source: Interval::with_length(source_range_start, range_length),
+            offset: destination_range_start - source_range_start,
+        }
+    }
+
+    fn map(&self, source_value: i64) -> DestinationValue {
+        if self.source.contains(source_value) {
+            DestinationValue::In(source_value + self.offset)
+        } else {
+            DestinationValue::Out(source_value)
+        }
+    }
+}
+
+/// A range such as `50 98 2`: the destination range start, the source range
+/// start and the range length. Both ranges have to end within an i64.
+fn almanac_range(input: Input<'_>) -> ParseResult<'_, AlmanacRange> {
+    let start = input;
+    let (destination_range_start, input) = number::<i64>(input)?;
+    let (source_range_start, input) = preceded(space0, number::<i64>)(input)?;
+    let (range_length, input) = preceded(space0, number)(input)?;
+    if destination_range_start.checked_add(range_length).is_none()
+        || source_range_start.checked_add(range_length).is_none()
+        || destination_range_start
+            .checked_sub(source_range_start)
+            .is_none()
+    {
+        return Err(start.error(start.line(), "ranges that end within an i64"));
+    }
+    let range = AlmanacRange::new(destination_range_start, source_range_start, range_length);
+    Ok((range, input))
+}
+
+#[derive(Default, Debug)]
+struct AlmanacEntry {
+    ranges: Vec<AlmanacRange>,
+    source_parameter: String,
+    destination_parameter: String,
+}
+
+impl AlmanacEntry {
+    fn new(source_parameter: &str, destination_parameter: &str) -> Self {
+        Self {
+            source_parameter: String::from(source_parameter),
+            destination_parameter: String::from(destination_parameter),
+            ..Self::default()
+        }
+    }
+
+    fn add_range(mut self, range: AlmanacRange) -> Self {
+        self.ranges.push(range);
+        self
+    }
+
+    fn map(&self, source_value: i64) -> i64 {
+        let destination_value_possibilities: Vec<_> =
+            self.ranges.iter().map(|r| r.map(source_value)).collect();
+
+        for destination_value in destination_value_possibilities {
+            if let DestinationValue::In(val) = destination_value {
+                return val;
+            }
+        }
+
+        source_value
+    }
+
+    /// Map every value in `source_values` at once. As with `map`, the first
+    /// range that holds a value maps it, and values in no range map to
+    /// themselves.
+    fn map_set(&self, source_values: &IntervalSet) -> IntervalSet {
+        let mut unmapped = source_values.clone();
+        let mut mapped = IntervalSet::new();
+        for range in self.ranges.iter() {
+            // the sets can be split into many intervals, each range
+            // splitting them further, so stop between ranges if asked to
+            cancel::check();
+            let sources = IntervalSet::from(range.source);
+            mapped = mapped.union(&unmapped.intersection(&sources).translate(range.offset));
+            unmapped = unmapped.difference(&sources);
+        }
+        mapped.union(&unmapped)
+    }
+}
+
+/// The header of a map, such as `seed-to-soil map:`, giving its source and
+/// destination parameters
+fn map_header(input: Input<'_>) -> ParseResult<'_, (&str, &str)> {
+    let parameter = take_while1(|c: char| c.is_ascii_alphabetic(), "a name");
+    let (source_parameter, input) = parameter(input)?;
+    let (_, input) = tag("-to-")(input)?;
+    let (destination_parameter, input) = parameter(input)?;
+    let (_, input) = tag(" map:")(input)?;
+    Ok(((source_parameter, destination_parameter), input))
+}
+
+/// A map header followed by a line for each of the map's ranges
+fn almanac_entry(input: Input<'_>) -> ParseResult<'_, AlmanacEntry> {
+    let ((source_parameter, destination_parameter), input) = line(map_header)(input)?;
+    let (ranges, input) = lines(almanac_range)(input)?;
+    let entry = ranges.into_iter().fold(
+        AlmanacEntry::new(source_parameter, destination_parameter),
+        AlmanacEntry::add_range,
+    );
+    Ok((entry, input))
+}
+
+/// What `parser` parses, along with the line it starts on, so that problems
+/// found once everything has been parsed can point at it
+fn with_line<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, (&'a str, T)> {
+    move |input: Input<'a>| {
+        let (value, rest) = parser(input)?;
+        Ok(((input.line(), value), rest))
+    }
+}
+
+/// The maps in the order they are followed to get from seeds to locations
+#[derive(Default, Debug)]
+pub struct Almanac {
+    entries: Vec<AlmanacEntry>,
+}
+
+impl Almanac {
+    /// Put the maps, given with their header lines, in the order they are
+    /// followed from seeds to locations. A map that leads nowhere, or back to
+    /// a parameter that has already been mapped, is an error at its header,
+    /// and a missing seed map is an error at the seeds line.
+    fn from_entries(
+        text: &str,
+        seeds_line: &str,
+        entries: Vec<(&str, AlmanacEntry)>,
+    ) -> Result<Self, ParseError> {
+        // as in the puzzle, a later map from a parameter replaces an earlier
+        // one
+        let mut maps: HashMap<String, (&str, AlmanacEntry)> = entries
+            .into_iter()
+            .map(|map| (map.1.source_parameter.clone(), map))
+            .collect();
+        let mut almanac = Self::default();
+        let mut parameter = String::from("seed");
+        let mut last_line = seeds_line;
+        while parameter != "location" {
+            let Some((header, entry)) = maps.remove(&parameter) else {
+                let expected = if almanac
+                    .entries
+                    .iter()
+                    .any(|entry| entry.source_parameter == parameter)
+                {
+                    String::from("a map leading to \"location\"")
+                } else {
+                    format!("a map from {:?} to follow", parameter)
+                };
+                return Err(ParseError::new(text, last_line, expected));
+            };
+            parameter = entry.destination_parameter.clone();
+            last_line = header;
+            almanac.entries.push(entry);
+        }
+        Ok(almanac)
+    }
+
+    /// Follow the maps from a seed to its location
+    fn map(&self, seed: i64) -> i64 {
+        self.entries
+            .iter()
+            .fold(seed, |value, entry| entry.map(value))
+    }
+
+    /// Follow the maps from every seed in `seeds` at once to their locations
+    fn map_set(&self, seeds: &IntervalSet) -> IntervalSet {
+        self.entries
+            .iter()
+            .fold(seeds.clone(), |values, entry| entry.map_set(&values))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_support::examples::load_examples;
+    use test_support::{
+        generated::check_generated_inputs,
+        property::{check, ints, vecs},
+        test_support::TestCase,
+    };
+
+    #[test]
+    fn test_map_range() {
+        let almanac_range = AlmanacRange::new(50, 98, 2);
+        assert_eq!(DestinationValue::In(50), almanac_range.map(98));
+        assert_eq!(DestinationValue::In(51), almanac_range.map(99));
+        assert_eq!(DestinationValue::Out(100), almanac_range.map(100));
+        assert_eq!(DestinationValue::Out(97), almanac_range.map(97));
+        let almanac_range = AlmanacRange::new(52, 50, 48);
+        assert_eq!(DestinationValue::Out(98), almanac_range.map(98));
+        assert_eq!(DestinationValue::Out(99), almanac_range.map(99));
+        assert_eq!(DestinationValue::Out(100), almanac_range.map(100));
+        assert_eq!(DestinationValue::In(97), almanac_range.map(95));
+        assert_eq!(DestinationValue::In(52), almanac_range.map(50));
+        assert_eq!(DestinationValue::In(99), almanac_range.map(97));
+    }
+
+    #[test]
+    fn test_entry_map_is_a_bijection() {
+        // 0..n is split into blocks, each with a length and a key; the blocks
+        // are sources in order and destinations in order of their keys, so
+        // the ranges cover 0..n as sources and as destinations
+        check(&vecs((ints(1..=5), ints(0..=9)), 1..=6), |blocks| {
+            let starts = |order: &[usize]| {
+                let mut starts = vec![0; blocks.len()];
+                let mut start = 0;
+                for &index in order {
+                    starts[index] = start;
+                    start += blocks[index].0;
+                }
+                starts
+            };
+            let source_order: Vec<usize> = (0..blocks.len()).collect();
+            let mut destination_order = source_order.clone();
+            destination_order.sort_by_key(|&index| (blocks[index].1, index));
+            let (sources, destinations) = (starts(&source_order), starts(&destination_order));
+
+            let entry = (0..blocks.len()).fold(AlmanacEntry::new("a", "b"), |entry, index| {
+                entry.add_range(AlmanacRange::new(
+                    destinations[index],
+                    sources[index],
+                    blocks[index].0,
+                ))
+            });
+            let n: i64 = blocks.iter().map(|(length, _)| length).sum();
+            let mut mapped: Vec<i64> = (0..n).map(|v| entry.map(v)).collect();
+            mapped.sort();
+            mapped == (0..n).collect::<Vec<_>>() && entry.map(-1) == -1 && entry.map(n) == n
+        });
+    }
+
+    #[test]
+    fn test_map_entry() {
+        let almanac_entry = AlmanacEntry::default()
+            .add_range(AlmanacRange::new(50, 98, 2))
+            .add_range(AlmanacRange::new(52, 50, 48));
+        assert_eq!(49, almanac_entry.map(49));
+        assert_eq!(52, almanac_entry.map(50));
+        assert_eq!(99, almanac_entry.map(97));
+        assert_eq!(50, almanac_entry.map(98));
+        assert_eq!(51, almanac_entry.map(99));
+        assert_eq!(100, almanac_entry.map(100));
+        assert_eq!(81, almanac_entry.map(79));
+        assert_eq!(14, almanac_entry.map(14));
+        assert_eq!(57, almanac_entry.map(55));
+        assert_eq!(13, almanac_entry.map(13));
+    }
+
+    #[test]
+    fn test_map_entry_set() {
+        let almanac_entry = AlmanacEntry::default()
+            .add_range(AlmanacRange::new(50, 98, 2))
+            .add_range(AlmanacRange::new(52, 50, 48));
+        let seeds = seed_ranges(&[79, 14, 55, 13, 45, 10]);
+        let mapped = almanac_entry.map_set(&seeds);
+        assert_eq!(
+            &[
+                Interval::new(45, 50),
+                Interval::new(52, 70),
+                Interval::new(81, 95),
+            ],
+            mapped.intervals()
+        );
+        // the same as mapping the seeds one at a time
+        for seed in 0..120 {
+            assert_eq!(
+                seeds.contains(seed),
+                mapped.contains(almanac_entry.map(seed))
+            );
+        }
+    }
+
+    #[test]
+    fn test_map_almanac() {
+        let almanac = Almanac {
+            entries: vec![
+                AlmanacEntry::new("seed", "soil")
+                    .add_range(AlmanacRange::new(50, 98, 2))
+                    .add_range(AlmanacRange::new(52, 50, 48)),
+                AlmanacEntry::new("soil", "fertilizer")
+                    .add_range(AlmanacRange::new(0, 15, 37))
+                    .add_range(AlmanacRange::new(37, 52, 2))
+                    .add_range(AlmanacRange::new(39, 0, 15)),
+            ],
+        };
+        assert_eq!(81, almanac.entries[0].map(79));
+        assert_eq!(81, almanac.map(79));
+        assert_eq!(53, almanac.map(14));
+        assert_eq!(
+            &[Interval::new(53, 54), Interval::new(81, 82)],
+            almanac.map_set(&seed_ranges(&[79, 1, 14, 1])).intervals()
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let test_cases = [
+            TestCase {
+                input: "seeds 79 14",
+                expected: "line 1, column 1: expected \"seeds:\", found \"seeds 79 14\"",
+            },
+            TestCase {
+                input: "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50",
+                expected: "line 5, column 6: expected a number, found nothing",
+            },
+            TestCase {
+                input: "seeds: 79 14\n\nseed-to-soil map:\n50 98 2 1",
+                expected: "line 4, column 9: expected the end of the line, found \"1\"",
+            },
+            TestCase {
+                input: "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n\nsoil to fertilizer map:",
+                expected: "line 6, column 5: expected \"-to-\", found \" to fertilizer map:\"",
+            },
+            TestCase {
+                input: "",
+                expected: "line 1, column 1: expected \"seeds:\", found nothing",
+            },
+            TestCase {
+                input: "seeds: 79 14 55\n\nseed-to-location map:\n50 98 2",
+                expected: "line 1, column 1: expected seed numbers in pairs of range start and length, found \"seeds: 79 14 55\"",
+            },
+            TestCase {
+                input: "seeds:\n\nseed-to-location map:\n50 98 2",
+                expected: "line 1, column 1: expected seed numbers in pairs of range start and length, found \"seeds:\"",
+            },
+            TestCase {
+                input: "seeds: 79 14\n\nsoil-to-location map:\n50 98 2",
+                expected: "line 1, column 1: expected a map from \"seed\" to follow, found \"seeds: 79 14\"",
+            },
+            TestCase {
+                input: "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n\nwater-to-location map:\n1 2 3",
+                expected: "line 3, column 1: expected a map from \"soil\" to follow, found \"seed-to-soil map:\"",
+            },
+            TestCase {
+                input: "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n\nsoil-to-seed map:\n1 2 3",
+                expected: "line 6, column 1: expected a map leading to \"location\", found \"soil-to-seed map:\"",
+            },
+            // part 2 has no seeds to map if every range is empty
+            TestCase {
+                input: "seeds: 79 0\n\nseed-to-location map:\n50 98 2",
+                expected: "line 1, column 11: expected a seed range length of at least 1, found \"0\"",
+            },
+            TestCase {
+                input: "seeds: 79 14 55  -3\n\nseed-to-location map:\n50 98 2",
+                expected: "line 1, column 18: expected a seed range length of at least 1, found \"-3\"",
+            },
+            TestCase {
+                input: "seeds: 9223372036854775800 14\n\nseed-to-location map:\n50 98 2",
+                expected: "line 1, column 28: expected a seed range length that ends the range within an i64, found \"14\"",
+            },
+            TestCase {
+                input: "seeds: 79 14\n\nseed-to-location map:\n50 9223372036854775800 14",
+                expected: "line 4, column 1: expected ranges that end within an i64, found \"50 9223372036854775800 14\"",
+            },
+        ];
+        for TestCase { input, expected } in test_cases {
+            assert_eq!(
+                expected,
+                Day05.parse(input).err().map(|e| e.to_string()).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        let mut checked = 0;
+        for example in load_examples(env!("CARGO_MANIFEST_DIR")) {
+            if let Some(expected) = example.expected(1) {
+                let answer = Day05.part1(&Day05.parse(&example.input).unwrap()).unwrap();
+                assert_eq!(*expected, answer, "{}", example.name);
+                checked += 1;
+            }
+        }
+        assert!(checked > 0, "no example has an answer to part 1");
+    }
+
+    #[test]
+    fn test_part2() {
+        let mut checked = 0;
+        for example in load_examples(env!("CARGO_MANIFEST_DIR")) {
+            if let Some(expected) = example.expected(2) {
+                let answer = Day05.part2(&Day05.parse(&example.input).unwrap()).unwrap();
+                assert_eq!(*expected, answer, "{}", example.name);
+                checked += 1;
+            }
+        }
+        assert!(checked > 0, "no example has an answer to part 2");
+    }
+
+    #[test]
+    fn test_generated_input() {
+        check_generated_inputs(&Day05, 20, |almanac, _, _| {
+            assert_eq!(40, almanac.seeds.len());
+        });
+    }
+}
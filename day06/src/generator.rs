@@ -0,0 +1,37 @@
+use aoc_support::rng::Rng;
+
+/// Part 2 reads the table as one race, so its time and distance must fit
+/// in an `i64`: four races of at most 99 ms and 4 digit distances do
+const MAX_RACES: usize = 4;
+
+/// A race table with `size` races (at most four), each with a record that
+/// can be beaten
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut times = vec![];
+    let mut distances = vec![];
+    for _ in 0..size.clamp(1, MAX_RACES) {
+        let time = rng.range(2..=99i64);
+        let best_distance = (time / 2) * (time - time / 2);
+        times.push(time.to_string());
+        distances.push(rng.range(0..=best_distance - 1).to_string());
+    }
+
+    let width = times
+        .iter()
+        .chain(&distances)
+        .map(|v| v.len())
+        .max()
+        .unwrap()
+        + 3;
+    let row = |label: &str, values: &[String]| {
+        values.iter().fold(format!("{:<9}", label), |row, value| {
+            format!("{}{:>width$}", row, value, width = width)
+        })
+    };
+    format!(
+        "{}\n{}\n",
+        row("Time:", &times),
+        row("Distance:", &distances)
+    )
+}
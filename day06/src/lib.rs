@@ -0,0 +1,259 @@
+pub mod generator;
+
+use aoc_support::{
+    answer::Answer,
+    combinators::{header, line, numbers, pair, parse_all, preceded},
+    parse::ParseError,
+    solution::{Solution, SolveError},
+};
+use math::count_quadratic_below_zero;
+
+const INPUT: &str = r#"Time:        58     99     64     69
+Distance:   478   2232   1019   1071"#;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Races;
+
+    fn day(&self) -> u32 {
+        6
+    }
+
+    fn embedded_input(&self) -> Option<&'static str> {
+        Some(INPUT)
+    }
+
+    fn generate_input(&self, seed: u64, size: usize) -> Option<String> {
+        Some(generator::generate(seed, size))
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Races::try_from(input)
+    }
+
+    fn part1(&self, races: &Self::Input) -> Result<Answer, SolveError> {
+        (0..races.race_times.len())
+            .map(|race_index| {
+                compute_record_breaking_combinations_for_race(
+                    races.race_times[race_index],
+                    races.record_distances[race_index],
+                )
+            })
+            .try_fold(1_i64, i64::checked_mul)
+            .map(Answer::from)
+            .ok_or(SolveError::new(
+                "the product of the ways to win each race doesn't fit in an i64",
+            ))
+    }
+
+    fn part2(&self, races: &Self::Input) -> Result<Answer, SolveError> {
+        // the spaces between the numbers are bad kerning, so there is
+        // really only one race
+        let joined = |values, what| {
+            join_digits(values).ok_or_else(|| {
+                SolveError::new(format!("the {} without spaces doesn't fit in an i64", what))
+            })
+        };
+        let race_time = joined(&races.race_times, "race time")?;
+        let record_distance = joined(&races.record_distances, "record distance")?;
+
+        Ok(compute_record_breaking_combinations_for_race(race_time, record_distance).into())
+    }
+}
+
+/// The time allowed for each race and the record distance travelled in it
+pub struct Races {
+    race_times: Vec<i64>,
+    record_distances: Vec<i64>,
+}
+
+impl TryFrom<&str> for Races {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let values = |label| line(preceded(header(label), numbers));
+        let (race_times, record_distances) =
+            parse_all(pair(values("Time"), values("Distance")), input)?;
+        // the times and distances are parsed from the first two lines
+        let mut lines = input.lines();
+        let (time_line, distance_line) = (lines.next().unwrap(), lines.next().unwrap());
+        if race_times.is_empty() {
+            return Err(ParseError::new(input, time_line, "at least one race time"));
+        }
+        if record_distances.len() != race_times.len() {
+            return Err(ParseError::new(
+                input,
+                distance_line,
+                format!(
+                    "a record distance for each of the {} races",
+                    race_times.len()
+                ),
+            ));
+        }
+        for (line, values, expected) in [
+            (time_line, &race_times, "a race time of at least 0"),
+            (
+                distance_line,
+                &record_distances,
+                "a record distance of at least 0",
+            ),
+        ] {
+            // the numbers after the label, as slices of the input for the
+            // error to point at
+            let tokens = line.split_ascii_whitespace().skip(1);
+            if let Some((token, _)) = tokens.zip(values).find(|(_, &value)| value < 0) {
+                return Err(ParseError::new(input, token, expected));
+            }
+        }
+
+        Ok(Races {
+            race_times,
+            record_distances,
+        })
+    }
+}
+
+/// Return the number written by writing out the digits of each of the
+/// values in turn, which can't be negative, or `None` if it doesn't fit in
+/// an i64
+fn join_digits(values: &[i64]) -> Option<i64> {
+    values.iter().try_fold(0_i64, |joined, &value| {
+        let digits = value.checked_ilog10().unwrap_or(0) + 1;
+        joined
+            .checked_mul(10_i64.checked_pow(digits)?)?
+            .checked_add(value)
+    })
+}
+
+fn compute_record_breaking_combinations_for_race(race_time: i64, record_distance: i64) -> i64 {
+    // the distance travelled by the boat is given by t * (R - t)
+    // where t is the time spent charging and R is the total race time
+    // We are looking for those times where t * (R - t) > D
+    // where D is the record distance travelled, that is where
+    // t^2 - R.t + D < 0
+    // with a = 1 nothing overflows an i128, and there are fewer winning
+    // times than there are times
+    count_quadratic_below_zero(1, -race_time, record_distance)
+        .expect("a race's quadratic fits in an i128") as i64
+}
+
+#[cfg(test)]
+mod tests {
+    const TEST_INPUT: &str = r#"Time:      7  15   30
+Distance:  9  40  200"#;
+    use super::*;
+    use test_support::{
+        generated::check_generated_inputs,
+        test_support::{dedent, TestCase},
+    };
+
+    #[test]
+    fn test_parse_errors() {
+        let test_cases = [
+            TestCase {
+                input: "Time: 7 15\nDistance: 9 4O",
+                expected: "line 2, column 13: expected a number, found \"4O\"",
+            },
+            TestCase {
+                input: "Time: 7 15",
+                expected: "line 1, column 11: expected \"Distance:\", found nothing",
+            },
+            TestCase {
+                input: "Times: 7 15\nDistance: 9 40",
+                expected: "line 1, column 1: expected \"Time:\", found \"Times: 7 15\"",
+            },
+            TestCase {
+                input: "Time: 7 15\nDistance: 9",
+                expected: "line 2, column 1: expected a record distance for each of the 2 races, \
+                found \"Distance: 9\"",
+            },
+            TestCase {
+                input: "Time:\nDistance:\n",
+                expected: "line 1, column 1: expected at least one race time, found \"Time:\"",
+            },
+            TestCase {
+                input: "Time: 7 -15\nDistance: 9 40",
+                expected: "line 1, column 9: expected a race time of at least 0, found \"-15\"",
+            },
+            TestCase {
+                input: "Time: 7 15\nDistance:  -9 40",
+                expected: "line 2, column 12: expected a record distance of at least 0, \
+                found \"-9\"",
+            },
+        ];
+        for TestCase { input, expected } in test_cases {
+            assert_eq!(
+                expected,
+                Day06.parse(input).err().map(|e| e.to_string()).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        let test_cases = [TestCase {
+            input: TEST_INPUT,
+            expected: 288,
+        }];
+        for TestCase { input, expected } in test_cases.iter() {
+            assert_eq!(
+                Day06.part1(&Day06.parse(&dedent(input)).unwrap()).unwrap(),
+                *expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_part2() {
+        let test_cases = [TestCase {
+            input: TEST_INPUT,
+            expected: 71503,
+        }];
+        for TestCase { input, expected } in test_cases.iter() {
+            assert_eq!(
+                Day06.part2(&Day06.parse(&dedent(input)).unwrap()).unwrap(),
+                *expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_join_digits() {
+        assert_eq!(Some(71530), join_digits(&[7, 15, 30]));
+        assert_eq!(Some(1005), join_digits(&[10, 0, 5]));
+        assert_eq!(Some(0), join_digits(&[]));
+        assert_eq!(Some(i64::MAX), join_digits(&[922, 3372036854775807]));
+        assert_eq!(None, join_digits(&[922, 3372036854775808]));
+        assert_eq!(None, join_digits(&[7000000000, 15000000000]));
+    }
+
+    #[test]
+    fn test_answers_wider_than_i64() {
+        let races = Day06
+            .parse("Time: 7000000000 15000000000\nDistance: 9 40")
+            .unwrap();
+        assert_eq!(
+            Err(SolveError::new(
+                "the product of the ways to win each race doesn't fit in an i64"
+            )),
+            Day06.part1(&races)
+        );
+        assert_eq!(
+            Err(SolveError::new(
+                "the race time without spaces doesn't fit in an i64"
+            )),
+            Day06.part2(&races)
+        );
+    }
+
+    #[test]
+    fn test_generated_input() {
+        check_generated_inputs(&Day06, 4, |races, part1, part2| {
+            assert_eq!(4, races.race_times.len());
+            // every record can be beaten
+            assert!(i64::try_from(&part1).unwrap() >= 1);
+            assert!(i64::try_from(&part2).unwrap() >= 1);
+        });
+    }
+}
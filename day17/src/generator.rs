@@ -0,0 +1,14 @@
+use aoc_support::rng::Rng;
+
+/// A map of heat loss `size` blocks square, with a heat loss of 1 to 9 in
+/// each block
+pub fn generate(seed: u64, size: usize) -> String {
+    let size = size.max(1);
+    let mut rng = Rng::new(seed);
+    let mut map = String::with_capacity(size * (size + 1));
+    for _ in 0..size {
+        map.extend((0..size).map(|_| char::from(rng.range(b'1'..=b'9'))));
+        map.push('\n');
+    }
+    map
+}
@@ -0,0 +1,461 @@
+pub mod generator;
+
+use aoc_support::{
+    answer::Answer,
+    cancel,
+    parse::ParseError,
+    solution::{Solution, SolveError},
+};
+use grid::{Grid, Location};
+use pathfinding::{astar, Path};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = DesertIslandMap;
+
+    fn day(&self) -> u32 {
+        17
+    }
+
+    fn generate_input(&self, seed: u64, size: usize) -> Option<String> {
+        Some(generator::generate(seed, size))
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        DesertIslandMap::try_from(input)
+    }
+
+    fn part1(&self, desert_island_map: &Self::Input) -> Result<Answer, SolveError> {
+        part1(desert_island_map).map(Answer::from).ok_or_else(|| {
+            SolveError::new(format!(
+                "the crucible can't reach the goal without going more than {} blocks in a \
+                straight line",
+                MAX_STRAIGHT_MOVES
+            ))
+        })
+    }
+
+    fn part2(&self, _desert_island_map: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(0.into())
+    }
+}
+
+#[derive(Debug)]
+pub struct DesertIslandMap {
+    heat_loss_map: Grid<i32>,
+}
+
+impl TryFrom<&str> for DesertIslandMap {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let heat_loss_map = Grid::parse(input, |c| {
+            c.to_digit(10)
+                .map(|heat_loss| heat_loss as i32)
+                .ok_or("a digit")
+        })?;
+        Ok(Self { heat_loss_map })
+    }
+}
+
+impl DesertIslandMap {
+    fn get_heat_loss(&self, location: &Location) -> i32 {
+        self.heat_loss_map[*location]
+    }
+
+    /// The blocks next to `location` with the direction of each from it, in
+    /// reading order
+    fn get_neighbours(&self, location: &Location) -> Vec<(AbsoluteDirection, Location)> {
+        self.heat_loss_map
+            .neighbours4(location)
+            .map(|neighbour| {
+                (
+                    AbsoluteDirection::compute_direction(location, &neighbour),
+                    neighbour,
+                )
+            })
+            .collect()
+    }
+
+    /// The crucible starts at the top left and has to reach the bottom right
+    fn get_start_and_goal(&self) -> (Location, Location) {
+        (
+            Location::default(),
+            Location::new(
+                self.heat_loss_map.num_rows() - 1,
+                self.heat_loss_map.num_cols() - 1,
+            ),
+        )
+    }
+
+    /// Render the map with the path part 1 finds drawn on it, if there is
+    /// one
+    pub fn draw_shortest_path(&self) -> Option<String> {
+        let (start, goal) = self.get_start_and_goal();
+        let path = shortest_path(self, &start, &goal)?;
+        let locations: Vec<_> = path.states.iter().map(|c| c.location).collect();
+        Some(self.draw(&locations))
+    }
+
+    /// Render the map with an arrow after each location on `path` but the
+    /// first, showing which way the path went into it
+    fn draw(&self, path: &[Location]) -> String {
+        let map = &self.heat_loss_map;
+        let mut directions = vec![AbsoluteDirection::None; map.len()];
+        for step in path.windows(2) {
+            directions[map.index_of(&step[1])] =
+                AbsoluteDirection::compute_direction(&step[0], &step[1]);
+        }
+
+        let mut drawing = String::new();
+        for (row, heat_losses) in map.rows().enumerate() {
+            let mut line = String::new();
+            for (col, heat_loss) in heat_losses.iter().enumerate() {
+                let index = map.index_of(&Location::new(row, col));
+                line.push_str(&format!(
+                    "{}{} ",
+                    heat_loss,
+                    directions[index].get_display_char()
+                ));
+            }
+            drawing.push_str(line.trim_end());
+            drawing.push('\n');
+        }
+        drawing
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+enum AbsoluteDirection {
+    North,
+    East,
+    South,
+    West,
+    None,
+}
+
+impl AbsoluteDirection {
+    fn compute_direction(from: &Location, to: &Location) -> Self {
+        if (from.row != to.row && from.col != to.col) || (from.row == to.row && from.col == to.col)
+        {
+            Self::None
+        } else if from.row == to.row {
+            if from.col < to.col {
+                Self::East
+            } else {
+                Self::West
+            }
+        } else if from.col == to.col {
+            if from.row < to.row {
+                Self::South
+            } else {
+                Self::North
+            }
+        } else {
+            Self::None
+        }
+    }
+
+    fn opposite(&self) -> Self {
+        match self {
+            Self::North => Self::South,
+            Self::East => Self::West,
+            Self::South => Self::North,
+            Self::West => Self::East,
+            Self::None => Self::None,
+        }
+    }
+
+    fn get_display_char(&self) -> char {
+        match self {
+            AbsoluteDirection::East => '>',
+            AbsoluteDirection::West => '<',
+            AbsoluteDirection::North => '^',
+            AbsoluteDirection::South => 'v',
+            _ => ' ',
+        }
+    }
+}
+
+/// The most blocks a crucible can move in a straight line
+const MAX_STRAIGHT_MOVES: u32 = 3;
+
+/// Where a crucible is and how it got there, which decides where it can go
+/// next
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+struct Crucible {
+    location: Location,
+    /// The direction of the last move, or `None` before the first
+    direction: AbsoluteDirection,
+    /// How many blocks in a row the crucible has moved in `direction`
+    straight_moves: u32,
+}
+
+impl Crucible {
+    /// The states the crucible can be in after one more move. It can't turn
+    /// back, and it has to turn after moving `MAX_STRAIGHT_MOVES` blocks in a
+    /// straight line.
+    fn moves(&self, desert_island_map: &DesertIslandMap) -> Vec<(Crucible, i32)> {
+        desert_island_map
+            .get_neighbours(&self.location)
+            .into_iter()
+            .filter(|(direction, _)| *direction != self.direction.opposite())
+            .filter(|(direction, _)| {
+                *direction != self.direction || self.straight_moves < MAX_STRAIGHT_MOVES
+            })
+            .map(|(direction, location)| {
+                let straight_moves = if direction == self.direction {
+                    self.straight_moves + 1
+                } else {
+                    1
+                };
+                let next = Crucible {
+                    location,
+                    direction,
+                    straight_moves,
+                };
+                (next, desert_island_map.get_heat_loss(&location))
+            })
+            .collect()
+    }
+}
+
+fn part1(desert_island_map: &DesertIslandMap) -> Option<i32> {
+    let (start, goal) = desert_island_map.get_start_and_goal();
+    shortest_path(desert_island_map, &start, &goal).map(|path| path.cost)
+}
+
+/// Find the path from `start` to `goal` that loses the least heat. There is
+/// none if the map is a single row or column too long to cross without
+/// turning.
+fn shortest_path(
+    desert_island_map: &DesertIslandMap,
+    start: &Location,
+    goal: &Location,
+) -> Option<Path<Crucible, i32>> {
+    let start = Crucible {
+        location: *start,
+        direction: AbsoluteDirection::None,
+        straight_moves: 0,
+    };
+    // each block left to the goal loses at least the least heat any block
+    // does, so this never overestimates the heat still to be lost. If a block
+    // loses none, the search is a plain Dijkstra.
+    let least_heat_loss = desert_island_map
+        .heat_loss_map
+        .cells()
+        .iter()
+        .copied()
+        .min()
+        .unwrap_or(0);
+    astar(
+        &start,
+        |crucible| {
+            // the search can be long on a big map, so stop if asked to
+            cancel::check();
+            crucible.moves(desert_island_map)
+        },
+        |crucible| crucible.location.manhattan_distance(goal) as i32 * least_heat_loss,
+        |crucible| crucible.location == *goal,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    const TEST_INPUT: &str = r#"2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533"#;
+
+    use super::*;
+    use pathfinding::dijkstra;
+    use test_support::{
+        assert_snapshot,
+        generated::check_generated_inputs,
+        property::{check, grids},
+        test_support::{dedent, TestCase},
+    };
+
+    #[test]
+    fn test_part1() {
+        let test_cases = [
+            TestCase {
+                input: TEST_INPUT,
+                expected: 102,
+            },
+            // the cheapest path loses no heat, but has to turn away from
+            // the goal to get round the 1
+            TestCase {
+                input: "0000\n0001\n0000",
+                expected: 0,
+            },
+        ];
+        for TestCase { input, expected } in test_cases.iter() {
+            assert_eq!(
+                Day17.part1(&Day17.parse(&dedent(input)).unwrap()).unwrap(),
+                *expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_part1_unreachable_goal() {
+        let desert_island_map = Day17.parse("1111\n").unwrap();
+        assert_eq!(Ok(Answer::from(3)), Day17.part1(&desert_island_map));
+
+        let desert_island_map = Day17.parse("11111\n").unwrap();
+        assert_eq!(
+            Err(SolveError::new(
+                "the crucible can't reach the goal without going more than 3 blocks in a \
+                straight line"
+            )),
+            Day17.part1(&desert_island_map)
+        );
+        assert_eq!(None, desert_island_map.draw_shortest_path());
+    }
+
+    #[test]
+    fn test_draw_shortest_path() {
+        let desert_island_map = Day17.parse(&dedent(TEST_INPUT)).unwrap();
+        let drawing = desert_island_map.draw_shortest_path().unwrap();
+        // the blocks with an arrow are those the path enters, so their heat
+        // loss adds up to part 1's answer, whatever the snapshot says
+        let heat_loss: u32 = drawing
+            .split_whitespace()
+            .filter(|block| block.len() > 1)
+            .filter_map(|block| block.chars().next()?.to_digit(10))
+            .sum();
+        assert_eq!(102, heat_loss);
+        assert_snapshot!("example_path", drawing);
+    }
+
+    #[test]
+    fn test_search_agrees_with_dijkstra() {
+        // plenty of blocks that lose no heat, which the distance to the goal
+        // alone would overestimate past
+        check(&grids("0000000123456789", 2..=8, 2..=8), |input| {
+            let desert_island_map = Day17.parse(input).unwrap();
+            let (start, goal) = desert_island_map.get_start_and_goal();
+            let expected = dijkstra(
+                &Crucible {
+                    location: start,
+                    direction: AbsoluteDirection::None,
+                    straight_moves: 0,
+                },
+                |crucible| crucible.moves(&desert_island_map),
+                |crucible| crucible.location == goal,
+            )
+            .unwrap();
+            shortest_path(&desert_island_map, &start, &goal)
+                .unwrap()
+                .cost
+                == expected.cost
+        });
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let test_cases = [
+            TestCase {
+                input: "241\n3x1",
+                expected: "line 2, column 2: expected a digit, found \"x\"",
+            },
+            TestCase {
+                input: "2413\n321",
+                expected: "line 2, column 1: expected a row of 4 cells, found \"321\"",
+            },
+            TestCase {
+                input: "",
+                expected: "line 1, column 1: expected a row of the grid, found nothing",
+            },
+        ];
+        for TestCase { input, expected } in test_cases {
+            assert_eq!(expected, Day17.parse(input).unwrap_err().to_string());
+        }
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let desert_island_map = DesertIslandMap::try_from(TEST_INPUT).unwrap();
+
+        let test_cases = [
+            TestCase {
+                input: Location::new(0, 0),
+                expected: 2,
+            },
+            TestCase {
+                input: Location::new(0, 1),
+                expected: 3,
+            },
+            TestCase {
+                input: Location::new(1, 1),
+                expected: 4,
+            },
+            TestCase {
+                input: Location::new(
+                    desert_island_map.heat_loss_map.num_rows() - 1,
+                    desert_island_map.heat_loss_map.num_cols() - 1,
+                ),
+                expected: 2,
+            },
+            TestCase {
+                input: Location::new(
+                    desert_island_map.heat_loss_map.num_rows() / 2,
+                    desert_island_map.heat_loss_map.num_cols() - 1,
+                ),
+                expected: 3,
+            },
+        ];
+
+        for TestCase { input, expected } in test_cases.iter() {
+            assert_eq!(*expected, desert_island_map.get_neighbours(input).len());
+        }
+    }
+
+    #[test]
+    #[ignore = "not yet implemented"]
+    fn test_part2() {
+        let test_cases = [
+            TestCase {
+                input: TEST_INPUT,
+                expected: 123,
+            },
+            TestCase {
+                input: "abc",
+                expected: 345,
+            },
+        ];
+        for TestCase { input, expected } in test_cases.iter() {
+            assert_eq!(
+                Day17.part2(&Day17.parse(&dedent(input)).unwrap()).unwrap(),
+                *expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_generated_input() {
+        check_generated_inputs(&Day17, 30, |desert_island_map, part1, _| {
+            assert_eq!(
+                (30, 30),
+                (
+                    desert_island_map.heat_loss_map.num_rows(),
+                    desert_island_map.heat_loss_map.num_cols()
+                )
+            );
+            // no worse than a staircase down the diagonal through 9s
+            let heat_loss = i32::try_from(&part1).unwrap();
+            assert!((58..=9 * 58).contains(&heat_loss));
+        });
+    }
+}
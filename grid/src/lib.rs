@@ -0,0 +1,401 @@
+use aoc_support::parse::ParseError;
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+/// The offsets of the four orthogonal neighbours of a cell, in reading order
+const NEIGHBOURS4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// The offsets of the eight neighbours of a cell, diagonals included, in
+/// reading order
+const NEIGHBOURS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A row and column in a grid, counting from the top left
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Location {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Location {
+    pub fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+
+    /// The number of orthogonal steps between the two locations
+    pub fn manhattan_distance(&self, other: &Location) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+/// A rectangular grid of cells, stored row by row
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid of `rows` rows of `cols` cells, taking the cells from `cells`
+    /// row by row. Panics if there isn't exactly one cell for each location.
+    pub fn new(rows: usize, cols: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            rows * cols,
+            cells.len(),
+            "a {}x{} grid needs {} cells",
+            rows,
+            cols,
+            rows * cols
+        );
+        Self { rows, cols, cells }
+    }
+
+    /// A grid of `rows` rows of `cols` cells that all hold `value`
+    pub fn filled(rows: usize, cols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(rows, cols, vec![value; rows * cols])
+    }
+
+    /// Parse a grid with a row for each line of `input` and a cell for each
+    /// character. `cell` reads a character, or returns what should have been
+    /// there instead (e.g. "a digit") if it can't. The rows must all be the
+    /// same length, and there must be at least one.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> Result<T, &'static str>,
+    ) -> Result<Self, ParseError> {
+        let lines: Vec<_> = input.lines().collect();
+        let Some(cols) = lines.first().map(|l| l.chars().count()) else {
+            return Err(ParseError::end_of(input, "a row of the grid"));
+        };
+
+        let mut cells = Vec::with_capacity(lines.len() * cols);
+        for line in lines.iter() {
+            for (index, c) in line.char_indices() {
+                match cell(c) {
+                    Ok(value) => cells.push(value),
+                    Err(expected) => {
+                        let found = &line[index..index + c.len_utf8()];
+                        return Err(ParseError::new(input, found, expected));
+                    }
+                }
+            }
+            if line.chars().count() != cols {
+                return Err(ParseError::new(
+                    input,
+                    line,
+                    format!("a row of {} cells", cols),
+                ));
+            }
+        }
+
+        Ok(Self::new(lines.len(), cols, cells))
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn num_cols(&self) -> usize {
+        self.cols
+    }
+
+    /// The number of cells in the grid
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Whether `location` is inside the grid
+    pub fn contains(&self, location: &Location) -> bool {
+        location.row < self.rows && location.col < self.cols
+    }
+
+    /// The location `row_offset` rows down and `col_offset` columns right of
+    /// `location`, if that is inside the grid
+    pub fn offset(
+        &self,
+        location: &Location,
+        row_offset: isize,
+        col_offset: isize,
+    ) -> Option<Location> {
+        let row = location.row.checked_add_signed(row_offset)?;
+        let col = location.col.checked_add_signed(col_offset)?;
+        let location = Location::new(row, col);
+        self.contains(&location).then_some(location)
+    }
+
+    /// The position of the cell at `location` counting row by row from the
+    /// top left, which is also its position in `cells`
+    pub fn index_of(&self, location: &Location) -> usize {
+        debug_assert!(self.contains(location));
+        location.row * self.cols + location.col
+    }
+
+    /// The location of the cell at position `index`, the inverse of
+    /// `index_of`
+    pub fn location_of(&self, index: usize) -> Location {
+        Location::new(index / self.cols, index % self.cols)
+    }
+
+    pub fn get(&self, location: &Location) -> Option<&T> {
+        self.contains(location)
+            .then(|| &self.cells[self.index_of(location)])
+    }
+
+    pub fn get_mut(&mut self, location: &Location) -> Option<&mut T> {
+        if self.contains(location) {
+            let index = self.index_of(location);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    /// The cells of the grid, row by row
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// Every location in the grid, row by row
+    pub fn locations(&self) -> impl Iterator<Item = Location> + '_ {
+        (0..self.cells.len()).map(|index| self.location_of(index))
+    }
+
+    /// Every cell in the grid with its location, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Location, &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| (self.location_of(index), cell))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// The rows of the grid, from the top
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.cells.chunks(self.cols.max(1))
+    }
+
+    /// The cells of column `col`, from the top
+    pub fn col(&self, col: usize) -> impl Iterator<Item = &T> + '_ {
+        assert!(col < self.cols, "column {} is outside the grid", col);
+        self.cells.iter().skip(col).step_by(self.cols)
+    }
+
+    /// The columns of the grid, from the left
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.cols).map(|col| self.col(col))
+    }
+
+    /// The cells from `top_left` to `bottom_right` inclusive, with their
+    /// locations, row by row. Parts of the region outside the grid are left
+    /// out.
+    pub fn region(
+        &self,
+        top_left: &Location,
+        bottom_right: &Location,
+    ) -> impl Iterator<Item = (Location, &T)> + '_ {
+        let rows = top_left.row..=bottom_right.row.min(self.rows.saturating_sub(1));
+        let cols = top_left.col..=bottom_right.col.min(self.cols.saturating_sub(1));
+        rows.flat_map(move |row| cols.clone().map(move |col| Location::new(row, col)))
+            .filter(|location| self.contains(location))
+            .map(|location| (location, &self[location]))
+    }
+
+    /// The up to four locations above, left of, right of and below
+    /// `location` that are inside the grid, in that order
+    pub fn neighbours4(&self, location: &Location) -> impl Iterator<Item = Location> + '_ {
+        self.neighbours(*location, &NEIGHBOURS4)
+    }
+
+    /// The up to eight locations around `location`, diagonals included,
+    /// that are inside the grid, in reading order
+    pub fn neighbours8(&self, location: &Location) -> impl Iterator<Item = Location> + '_ {
+        self.neighbours(*location, &NEIGHBOURS8)
+    }
+
+    fn neighbours(
+        &self,
+        location: Location,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Location> + '_ {
+        offsets
+            .iter()
+            .filter_map(move |&(row, col)| self.offset(&location, row, col))
+    }
+
+    /// A grid of the same shape with `f` applied to each cell
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.rows, self.cols, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T> Index<Location> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, location: Location) -> &T {
+        self.get(&location).unwrap_or_else(|| {
+            panic!(
+                "{:?} is outside the {}x{} grid",
+                location, self.rows, self.cols
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Location> for Grid<T> {
+    fn index_mut(&mut self, location: Location) -> &mut T {
+        let (rows, cols) = (self.rows, self.cols);
+        self.get_mut(&location)
+            .unwrap_or_else(|| panic!("{:?} is outside the {}x{} grid", location, rows, cols))
+    }
+}
+
+/// Each row on its own line, with the cells written one after another
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_support::test_support::{run_tests, TestCase};
+
+    const TEST_INPUT: &str = "123\n456\n789\nabc\n";
+
+    fn parse_chars(input: &str) -> Grid<char> {
+        Grid::parse(input, Ok).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse("123\n456\n", |c| c.to_digit(10).ok_or("a digit")).unwrap();
+        assert_eq!((2, 3), (grid.num_rows(), grid.num_cols()));
+        assert_eq!(&[1, 2, 3, 4, 5, 6], grid.cells());
+        assert_eq!("123\n456\n", grid.to_string());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let test_cases = [
+            TestCase {
+                input: "",
+                expected: "line 1, column 1: expected a row of the grid, found nothing",
+            },
+            TestCase {
+                input: "12\n3x",
+                expected: "line 2, column 2: expected a digit, found \"x\"",
+            },
+            TestCase {
+                input: "12\n345",
+                expected: "line 2, column 1: expected a row of 2 cells, found \"345\"",
+            },
+        ];
+        run_tests(
+            |input| {
+                Grid::parse(input, |c| c.to_digit(10).ok_or("a digit"))
+                    .unwrap_err()
+                    .to_string()
+            },
+            &test_cases,
+        );
+    }
+
+    #[test]
+    fn test_index_and_location() {
+        let grid = parse_chars(TEST_INPUT);
+        assert_eq!(12, grid.len());
+        for (index, location) in grid.locations().enumerate() {
+            assert_eq!(index, grid.index_of(&location));
+            assert_eq!(location, grid.location_of(index));
+        }
+        assert_eq!(Location::new(3, 0), grid.location_of(9));
+        assert_eq!('8', grid[Location::new(2, 1)]);
+        assert_eq!(None, grid.get(&Location::new(0, 3)));
+        assert_eq!(None, grid.get(&Location::new(4, 0)));
+    }
+
+    #[test]
+    fn test_offset() {
+        let grid = parse_chars(TEST_INPUT);
+        let corner = Location::new(0, 2);
+        assert_eq!(Some(Location::new(1, 1)), grid.offset(&corner, 1, -1));
+        assert_eq!(None, grid.offset(&corner, -1, 0));
+        assert_eq!(None, grid.offset(&corner, 0, 1));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = parse_chars(TEST_INPUT);
+        let neighbours = |location: Location, eight: bool| {
+            let locations: Vec<_> = if eight {
+                grid.neighbours8(&location).collect()
+            } else {
+                grid.neighbours4(&location).collect()
+            };
+            locations.iter().map(|l| grid[*l]).collect::<String>()
+        };
+        assert_eq!("2468", neighbours(Location::new(1, 1), false));
+        assert_eq!("12346789", neighbours(Location::new(1, 1), true));
+        assert_eq!("24", neighbours(Location::new(0, 0), false));
+        assert_eq!("245", neighbours(Location::new(0, 0), true));
+        assert_eq!("789ac", neighbours(Location::new(3, 1), true));
+    }
+
+    #[test]
+    fn test_rows_cols_and_regions() {
+        let mut grid = parse_chars(TEST_INPUT);
+        let rows: Vec<String> = grid.rows().map(|r| r.iter().collect()).collect();
+        assert_eq!(vec!["123", "456", "789", "abc"], rows);
+        let cols: Vec<String> = grid.cols().map(|c| c.collect()).collect();
+        assert_eq!(vec!["147a", "258b", "369c"], cols);
+
+        let region: String = grid
+            .region(&Location::new(2, 1), &Location::new(5, 5))
+            .map(|(_, c)| c)
+            .collect();
+        assert_eq!("89bc", region);
+
+        grid[Location::new(3, 2)] = 'C';
+        assert_eq!("abC", grid.row(3).iter().collect::<String>());
+        assert_eq!(
+            "123\n456\n789\nabC\n",
+            grid.map(|c| c.to_string()).to_string()
+        );
+    }
+
+    #[test]
+    fn test_manhattan_distance() {
+        assert_eq!(
+            5,
+            Location::new(1, 4).manhattan_distance(&Location::new(3, 1))
+        );
+    }
+}
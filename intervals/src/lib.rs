@@ -0,0 +1,350 @@
+use std::{
+    fmt::Debug,
+    ops::{Add, Sub},
+};
+
+/// The types an interval can be made of: integers, in practice
+pub trait Endpoint: Copy + Ord + Debug + Add<Output = Self> + Sub<Output = Self> {}
+
+impl<T: Copy + Ord + Debug + Add<Output = T> + Sub<Output = T>> Endpoint for T {}
+
+/// The values from `start` up to but not including `end`. An interval whose
+/// end isn't after its start is empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T = i64> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Endpoint> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    /// The `length` values starting from `start`
+    pub fn with_length(start: T, length: T) -> Self {
+        Self::new(start, start + length)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    /// The number of values in the interval, which is negative or zero if it
+    /// is empty
+    pub fn len(&self) -> T {
+        self.end - self.start
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Whether the intervals have any value in common
+    pub fn overlaps(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// The values in both intervals, which may be empty
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// The values before `point` and the values from `point` on. Either may
+    /// be empty.
+    pub fn split_at(&self, point: T) -> (Self, Self) {
+        let point = point.clamp(self.start, self.end.max(self.start));
+        (Self::new(self.start, point), Self::new(point, self.end))
+    }
+
+    /// The interval moved along by `offset`
+    pub fn translate(&self, offset: T) -> Self {
+        Self::new(self.start + offset, self.end + offset)
+    }
+}
+
+/// A set of values held as the fewest intervals that cover them: sorted,
+/// not empty, and neither overlapping nor touching
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T = i64> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { intervals: vec![] }
+    }
+}
+
+impl<T: Endpoint> IntervalSet<T> {
+    /// An empty set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The intervals covering the set, in order
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The smallest value in the set
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    /// The end of the set's last interval, one past its largest value
+    pub fn max_end(&self) -> Option<T> {
+        self.intervals.last().map(|i| i.end)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        // the last interval starting at or before `value` is the only one
+        // that can contain it
+        let after = self.intervals.partition_point(|i| i.start <= value);
+        after > 0 && self.intervals[after - 1].contains(value)
+    }
+
+    /// Add the values in `interval` to the set
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+
+        // the intervals that overlap or touch `interval` are merged with it
+        let first = self.intervals.partition_point(|i| i.end < interval.start);
+        let last = self.intervals.partition_point(|i| i.start <= interval.end);
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |merged, i| {
+                Interval::new(merged.start.min(i.start), merged.end.max(i.end))
+            });
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    /// The values in either set
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for interval in other.intervals.iter() {
+            union.insert(*interval);
+        }
+        union
+    }
+
+    /// The values in both sets
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intersection = vec![];
+        let (mut a, mut b) = (0, 0);
+        while a < self.intervals.len() && b < other.intervals.len() {
+            let overlap = self.intervals[a].intersection(&other.intervals[b]);
+            if !overlap.is_empty() {
+                intersection.push(overlap);
+            }
+            // move past whichever interval ends first; it can't overlap
+            // anything further on in the other set
+            if self.intervals[a].end < other.intervals[b].end {
+                a += 1;
+            } else {
+                b += 1;
+            }
+        }
+        Self {
+            intervals: intersection,
+        }
+    }
+
+    /// The values in this set that aren't in `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = vec![];
+        let mut others = other.intervals.iter().peekable();
+        for interval in self.intervals.iter() {
+            let mut rest = *interval;
+            while let Some(cut) = others.peek() {
+                if cut.end <= rest.start {
+                    others.next();
+                    continue;
+                }
+                if cut.start >= rest.end {
+                    break;
+                }
+                let (before, _) = rest.split_at(cut.start);
+                if !before.is_empty() {
+                    difference.push(before);
+                }
+                rest = rest.split_at(cut.end).1;
+                if rest.is_empty() {
+                    break;
+                }
+                others.next();
+            }
+            if !rest.is_empty() {
+                difference.push(rest);
+            }
+        }
+        Self {
+            intervals: difference,
+        }
+    }
+
+    /// The values before `point` and the values from `point` on
+    pub fn split_at(&self, point: T) -> (Self, Self) {
+        let mut before = Self::new();
+        let mut after = Self::new();
+        for interval in self.intervals.iter() {
+            let (low, high) = interval.split_at(point);
+            before.insert(low);
+            after.insert(high);
+        }
+        (before, after)
+    }
+
+    /// The set with every value moved along by `offset`
+    pub fn translate(&self, offset: T) -> Self {
+        Self {
+            intervals: self.intervals.iter().map(|i| i.translate(offset)).collect(),
+        }
+    }
+}
+
+impl<T: Endpoint> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(interval);
+        set
+    }
+}
+
+impl<T: Endpoint> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(intervals: I) -> Self {
+        let mut set = Self::new();
+        for interval in intervals {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_support::{
+        property::{check, ints, vecs, Ints, Vecs},
+        test_support::{run_tests, TestCase},
+    };
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end))
+            .collect()
+    }
+
+    #[test]
+    fn test_interval() {
+        let interval = Interval::with_length(98, 2);
+        assert_eq!(Interval::new(98, 100), interval);
+        assert_eq!(2, interval.len());
+        assert!(interval.contains(98) && interval.contains(99));
+        assert!(!interval.contains(97) && !interval.contains(100));
+        assert!(Interval::new(3, 3).is_empty());
+        assert!(Interval::new(4, 3).is_empty());
+
+        assert_eq!(
+            Interval::new(99, 100),
+            interval.intersection(&Interval::new(99, 150))
+        );
+        assert!(!interval.overlaps(&Interval::new(100, 101)));
+        assert_eq!(Interval::new(50, 52), interval.translate(-48));
+    }
+
+    #[test]
+    fn test_interval_split_at() {
+        let test_cases = [
+            TestCase {
+                input: 5,
+                expected: (Interval::new(2, 5), Interval::new(5, 8)),
+            },
+            TestCase {
+                input: 0,
+                expected: (Interval::new(2, 2), Interval::new(2, 8)),
+            },
+            TestCase {
+                input: 10,
+                expected: (Interval::new(2, 8), Interval::new(8, 8)),
+            },
+        ];
+        run_tests(|&point| Interval::new(2, 8).split_at(point), &test_cases);
+    }
+
+    #[test]
+    fn test_insert_normalizes() {
+        let set = set(&[(10, 20), (0, 5), (5, 7), (30, 30), (18, 25), (40, 45)]);
+        assert_eq!(
+            &[
+                Interval::new(0, 7),
+                Interval::new(10, 25),
+                Interval::new(40, 45)
+            ],
+            set.intervals()
+        );
+        assert_eq!(Some(0), set.min());
+        assert_eq!(Some(45), set.max_end());
+        assert!(set.contains(24) && !set.contains(25) && !set.contains(-1));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25), (28, 40)]);
+        assert_eq!(set(&[(0, 40)]), a.union(&b));
+        assert_eq!(set(&[(5, 10), (20, 25), (28, 30)]), a.intersection(&b));
+        assert_eq!(set(&[(0, 5), (25, 28)]), a.difference(&b));
+        assert_eq!(set(&[(10, 20), (30, 40)]), b.difference(&a));
+        assert_eq!(
+            (set(&[(0, 10), (20, 22)]), set(&[(22, 30)])),
+            a.split_at(22)
+        );
+        assert_eq!(set(&[(-5, 5), (15, 25)]), a.translate(-5));
+    }
+
+    /// Sets of up to four intervals between -10 and 10
+    fn sets() -> Vecs<(Ints<i64>, Ints<i64>)> {
+        vecs((ints(-10..=10), ints(-10..=10)), 0..=4)
+    }
+
+    #[test]
+    fn test_set_operations_match_membership() {
+        let strategy = (sets(), sets(), ints(-12..=12i64));
+        let to_set = |intervals: &Vec<(i64, i64)>| set(intervals);
+        check(&strategy, |(a, b, point)| {
+            let (a_set, b_set) = (to_set(a), to_set(b));
+            let in_a = |v| a.iter().any(|&(start, end)| start <= v && v < end);
+            let in_b = |v| b.iter().any(|&(start, end)| start <= v && v < end);
+            let (before, after) = a_set.split_at(*point);
+            let normalized = |s: &IntervalSet| {
+                s.intervals().windows(2).all(|w| w[0].end < w[1].start)
+                    && s.intervals().iter().all(|i| !i.is_empty())
+            };
+            (-12..=12).all(|v| {
+                a_set.contains(v) == in_a(v)
+                    && a_set.union(&b_set).contains(v) == (in_a(v) || in_b(v))
+                    && a_set.intersection(&b_set).contains(v) == (in_a(v) && in_b(v))
+                    && a_set.difference(&b_set).contains(v) == (in_a(v) && !in_b(v))
+                    && before.contains(v) == (in_a(v) && v < *point)
+                    && after.contains(v) == (in_a(v) && v >= *point)
+                    && a_set.translate(3).contains(v + 3) == in_a(v)
+            }) && [
+                a_set.union(&b_set),
+                a_set.intersection(&b_set),
+                a_set.difference(&b_set),
+                before,
+                after,
+            ]
+            .iter()
+            .all(normalized)
+        });
+    }
+}
@@ -0,0 +1,357 @@
+/// The largest integer whose square is at most `n`
+pub fn isqrt_u128(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+
+    // Newton's method from above: each step moves down towards the root
+    // until the next step would go back up
+    let mut x = 1 << (n.ilog2() / 2 + 1);
+    loop {
+        let next = (x + n / x) / 2;
+        if next >= x {
+            return x;
+        }
+        x = next;
+    }
+}
+
+/// The largest integer whose square is at most `n`
+pub fn isqrt_u64(n: u64) -> u64 {
+    isqrt_u128(u128::from(n)) as u64
+}
+
+/// The number of integers `x` for which `a*x^2 + b*x + c < 0`, where `a` is
+/// positive so there are finitely many. The roots are found with exact
+/// integer arithmetic, so there are no floating point boundaries to patch.
+/// Gives `None` if the arithmetic would overflow an `i128`, or the count a
+/// `u64`, which only happens with coefficients near the ends of the `i64`
+/// range.
+pub fn count_quadratic_below_zero(a: i64, b: i64, c: i64) -> Option<u64> {
+    assert!(
+        a > 0,
+        "the quadratic must open upwards to have finitely many solutions"
+    );
+    let (a, b, c) = (i128::from(a), i128::from(b), i128::from(c));
+    let f = |x: i128| {
+        a.checked_mul(x)?
+            .checked_add(b)?
+            .checked_mul(x)?
+            .checked_add(c)
+    };
+
+    let discriminant = b
+        .checked_mul(b)?
+        .checked_sub(a.checked_mul(c)?.checked_mul(4)?)?;
+    if discriminant <= 0 {
+        return Some(0);
+    }
+
+    // these are at most a step or two outside the solutions, because the
+    // square root is rounded down
+    let root = isqrt_u128(discriminant as u128) as i128;
+    let mut low = (-b - root).div_euclid(2 * a);
+    let mut high = (-b + root).div_euclid(2 * a) + 1;
+    while low <= high && f(low)? >= 0 {
+        low += 1;
+    }
+    while high >= low && f(high)? >= 0 {
+        high -= 1;
+    }
+
+    if low > high {
+        Some(0)
+    } else {
+        u64::try_from(high - low + 1).ok()
+    }
+}
+
+/// The greatest common divisor of `a` and `b`, or 0 if both are 0
+pub fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// The least common multiple of `a` and `b`, or 0 if either is 0
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        0
+    } else {
+        a / gcd(a, b) * b
+    }
+}
+
+/// The greatest common divisor of all the values, or 0 if there are none
+pub fn gcd_of(values: impl IntoIterator<Item = u64>) -> u64 {
+    values.into_iter().fold(0, gcd)
+}
+
+/// The least common multiple of all the values, or 1 if there are none
+pub fn lcm_of(values: impl IntoIterator<Item = u64>) -> u64 {
+    values.into_iter().fold(1, lcm)
+}
+
+/// The greatest common divisor `g` of `a` and `b` (which is never negative),
+/// with `x` and `y` such that `a*x + b*y = g`
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Solve the congruences `x = residue (mod modulus)` together with the
+/// Chinese remainder theorem, returning `(x, m)` where the solutions are the
+/// values equal to `x` mod `m` and `0 <= x < m`. The moduli must be
+/// positive but needn't be coprime; there is no solution if congruences with
+/// a common factor disagree. Gives `None` too if `m` doesn't fit in an `i64`.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let mut solution: (i128, i128) = (0, 1);
+    for &(residue, modulus) in congruences {
+        assert!(modulus > 0, "moduli must be positive");
+        let (x, m) = solution;
+        let (residue, modulus) = (i128::from(residue), i128::from(modulus));
+
+        // x + m*k = residue (mod modulus), so m*k = residue - x
+        let (g, inverse, _) = extended_gcd(
+            i64::try_from(m.rem_euclid(modulus)).ok()?,
+            i64::try_from(modulus).ok()?,
+        );
+        let g = i128::from(g);
+        let difference = residue - x;
+        if difference % g != 0 {
+            return None;
+        }
+
+        let step = modulus / g;
+        let k = (difference / g).rem_euclid(step) * i128::from(inverse).rem_euclid(step) % step;
+        // m only grows, so once it is too big for an i64 it stays too big
+        let combined = m
+            .checked_mul(step)
+            .filter(|&combined| combined <= i128::from(i64::MAX))?;
+        solution = ((x + m * k).rem_euclid(combined), combined);
+    }
+
+    let (x, m) = solution;
+    Some((i64::try_from(x).ok()?, i64::try_from(m).ok()?))
+}
+
+/// `base` to the power `exponent`, mod `modulus`
+pub fn mod_pow(base: u64, exponent: u64, modulus: u64) -> u64 {
+    assert!(modulus > 0, "the modulus must be positive");
+    let modulus = u128::from(modulus);
+    let mut result = 1 % modulus;
+    let mut base = u128::from(base) % modulus;
+    let mut exponent = exponent;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+    result as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_support::{
+        property::{check, ints, vecs},
+        test_support::{run_tests, TestCase},
+    };
+
+    #[test]
+    fn test_isqrt() {
+        check(&ints(0..=u64::MAX), |&n| {
+            let root = u128::from(isqrt_u64(n));
+            root * root <= u128::from(n) && (root + 1) * (root + 1) > u128::from(n)
+        });
+        check(&ints(0..=u64::MAX), |&n| {
+            // squares near the top of the u128 range
+            let n = u128::from(n) << 64 | u128::from(n);
+            let root = isqrt_u128(n);
+            root * root <= n && (root + 1).checked_mul(root + 1).is_none_or(|s| s > n)
+        });
+        assert_eq!(u64::from(u32::MAX), isqrt_u64(u64::MAX));
+        assert_eq!(u128::from(u64::MAX), isqrt_u128(u128::MAX));
+    }
+
+    #[test]
+    fn test_count_quadratic_below_zero() {
+        let test_cases = [
+            // the day 6 example races: t*(R - t) > D
+            TestCase {
+                input: (1, -7, 9),
+                expected: 4,
+            },
+            TestCase {
+                input: (1, -15, 40),
+                expected: 8,
+            },
+            // the roots are exactly 10 and 20, which don't count
+            TestCase {
+                input: (1, -30, 200),
+                expected: 9,
+            },
+            TestCase {
+                input: (1, 0, 0),
+                expected: 0,
+            },
+            TestCase {
+                input: (4, -4, 0),
+                expected: 0,
+            },
+        ];
+        run_tests(
+            |&(a, b, c)| count_quadratic_below_zero(a, b, c).unwrap(),
+            &test_cases,
+        );
+
+        check(
+            &(ints(1..=i64::MAX), ints(-50..=50i64), ints(-50..=50i64)),
+            |&(a, b, c)| {
+                let (a, b, c) = (i128::from(a), i128::from(b), i128::from(c));
+                let brute_force = (-100..=100).filter(|x| a * x * x + b * x + c < 0).count() as u64;
+                count_quadratic_below_zero(a as i64, b as i64, c as i64) == Some(brute_force)
+            },
+        );
+
+        // (x - low) * (x - high), with roots big enough that the
+        // coefficients are near the ends of the i64 range
+        let roots = || ints(-3_000_000_000..=3_000_000_000i64);
+        check(&(roots(), roots()), |&(low, high)| {
+            let (low, high) = (low.min(high), low.max(high));
+            let count = count_quadratic_below_zero(1, -(low + high), low * high);
+            count == Some((high - low - 1).max(0) as u64)
+        });
+        assert_eq!(
+            Some(i64::MAX as u64),
+            count_quadratic_below_zero(1, i64::MIN, 0)
+        );
+
+        // the discriminant is too big for an i128
+        assert_eq!(None, count_quadratic_below_zero(i64::MAX, 0, i64::MIN));
+        let extreme = || ints(i64::MIN..=i64::MAX);
+        check(&(ints(1..=i64::MAX), extreme(), extreme()), |&(a, b, c)| {
+            // the integers nearest the vertex are the lowest, and this close
+            // to the vertex nothing overflows an i128
+            let (a, b, c) = (i128::from(a), i128::from(b), i128::from(c));
+            let vertex = (-b).div_euclid(2 * a);
+            let lowest = [vertex, vertex + 1]
+                .map(|x| a * x * x + b * x + c)
+                .into_iter()
+                .min()
+                .unwrap();
+            count_quadratic_below_zero(a as i64, b as i64, c as i64)
+                .is_none_or(|count| (count > 0) == (lowest < 0))
+        });
+    }
+
+    #[test]
+    fn test_gcd_and_lcm() {
+        check(&(ints(0..=200u64), ints(0..=200u64)), |&(a, b)| {
+            let brute_force_gcd = (1..=a.max(b))
+                .filter(|d| a % d == 0 && b % d == 0)
+                .max()
+                .unwrap_or(0);
+            let brute_force_lcm = if a == 0 || b == 0 {
+                0
+            } else {
+                (1..=a * b).find(|m| m % a == 0 && m % b == 0).unwrap()
+            };
+            gcd(a, b) == brute_force_gcd && lcm(a, b) == brute_force_lcm
+        });
+        assert_eq!(6, gcd_of([12, 18, 30]));
+        assert_eq!(0, gcd_of([]));
+        assert_eq!(180, lcm_of([12, 18, 30]));
+        assert_eq!(1, lcm_of([]));
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        check(
+            &(ints(-1000..=1000i64), ints(-1000..=1000i64)),
+            |&(a, b)| {
+                let (g, x, y) = extended_gcd(a, b);
+                g == gcd(a.unsigned_abs(), b.unsigned_abs()) as i64 && a * x + b * y == g
+            },
+        );
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(Some((23, 105)), crt(&[(2, 3), (3, 5), (2, 7)]));
+        assert_eq!(Some((0, 1)), crt(&[]));
+        // moduli with a common factor
+        assert_eq!(Some((10, 12)), crt(&[(4, 6), (2, 4)]));
+        assert_eq!(None, crt(&[(1, 6), (2, 4)]));
+
+        check(
+            &vecs((ints(-20..=20i64), ints(1..=12i64)), 0..=3),
+            |congruences| {
+                let modulus: i64 = congruences.iter().map(|&(_, m)| m).product();
+                let brute_force =
+                    (0..modulus).find(|x| congruences.iter().all(|&(r, m)| (x - r) % m == 0));
+                match crt(congruences) {
+                    Some((x, m)) => {
+                        brute_force == Some(x)
+                            && (0..modulus).all(|y| {
+                                congruences.iter().all(|&(r, m)| (y - r) % m == 0)
+                                    == ((y - x) % m == 0)
+                            })
+                    }
+                    None => brute_force.is_none(),
+                }
+            },
+        );
+
+        // moduli near i64::MAX, whose product overflows even an i128
+        assert_eq!(
+            None,
+            crt(&[(1, i64::MAX), (2, i64::MAX - 1), (3, i64::MAX - 2)])
+        );
+        assert_eq!(
+            Some((i64::MAX - 1, i64::MAX)),
+            crt(&[(-1, i64::MAX), (i64::MAX - 1, i64::MAX)])
+        );
+        check(
+            &vecs((ints(i64::MIN..=i64::MAX), ints(1..=i64::MAX)), 0..=4),
+            |congruences| match crt(congruences) {
+                Some((x, m)) => {
+                    (0..m).contains(&x)
+                        && congruences.iter().all(|&(r, modulus)| {
+                            (i128::from(x) - i128::from(r)).rem_euclid(i128::from(modulus)) == 0
+                        })
+                }
+                None => true,
+            },
+        );
+    }
+
+    #[test]
+    fn test_mod_pow() {
+        check(
+            &(ints(0..=1000u64), ints(0..=30u64), ints(1..=1000u64)),
+            |&(base, exponent, modulus)| {
+                let brute_force = (0..exponent).fold(1 % modulus, |p, _| p * base % modulus);
+                mod_pow(base, exponent, modulus) == brute_force
+            },
+        );
+        assert_eq!(1, mod_pow(u64::MAX, u64::MAX, u64::MAX - 1));
+    }
+}
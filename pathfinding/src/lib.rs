@@ -0,0 +1,289 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// A path found by a search, and what it cost
+#[derive(Debug, Clone, PartialEq)]
+pub struct Path<S, C> {
+    /// The states on the path, from the start to the goal inclusive
+    pub states: Vec<S>,
+    /// The total cost of the steps along the path
+    pub cost: C,
+}
+
+/// The cost of a step in a search. Costs must not be negative, and the
+/// default is zero.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<C: Copy + Ord + Add<Output = C> + Default> Cost for C {}
+
+/// A state waiting to be visited. The heap pops the smallest estimated total
+/// cost first, and of those the one furthest from the start.
+struct Visit<S, C> {
+    estimate: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> Ord for Visit<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Visit<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Visit<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S, C: Ord> Eq for Visit<S, C> {}
+
+/// Find the cheapest path from `start` to a state for which `is_goal` is
+/// true. `successors` gives the states one step on from a state, each with
+/// the cost of that step.
+pub fn dijkstra<S, C, I>(
+    start: &S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// Find the cheapest path from `start` to a state for which `is_goal` is
+/// true, like `dijkstra`, exploring first the states that `heuristic`
+/// estimates are closest to a goal. The path is only sure to be the cheapest
+/// if the heuristic never overestimates the cost of reaching a goal.
+pub fn astar<S, C, I>(
+    start: &S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut costs = HashMap::from([(start.clone(), C::default())]);
+    let mut parents = HashMap::new();
+    let mut heap = BinaryHeap::from([Visit {
+        estimate: heuristic(start),
+        cost: C::default(),
+        state: start.clone(),
+    }]);
+
+    while let Some(Visit { cost, state, .. }) = heap.pop() {
+        // a cheaper way to this state has been visited already
+        if cost > costs[&state] {
+            continue;
+        }
+
+        if is_goal(&state) {
+            return Some(Path {
+                states: reconstruct_path(&parents, &state),
+                cost,
+            });
+        }
+
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            if costs.get(&next).is_none_or(|&known| next_cost < known) {
+                costs.insert(next.clone(), next_cost);
+                parents.insert(next.clone(), state.clone());
+                heap.push(Visit {
+                    estimate: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    state: next,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+/// Find a path from `start` to a state for which `is_goal` is true with the
+/// fewest steps. `successors` gives the states one step on from a state. The
+/// cost of the path is its number of steps.
+pub fn bfs<S, I>(
+    start: &S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut parents = HashMap::new();
+    let mut queue = VecDeque::from([start.clone()]);
+    let mut seen = HashMap::from([(start.clone(), 0)]);
+
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            return Some(Path {
+                states: reconstruct_path(&parents, &state),
+                cost: seen[&state],
+            });
+        }
+
+        let steps = seen[&state] + 1;
+        for next in successors(&state) {
+            if !seen.contains_key(&next) {
+                seen.insert(next.clone(), steps);
+                parents.insert(next.clone(), state.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// The path to `goal` recorded in `parents`, which maps each state reached
+/// in a search to the state it was reached from. The path starts at the
+/// state that has no parent and ends at `goal`.
+pub fn reconstruct_path<S: Clone + Eq + Hash>(parents: &HashMap<S, S>, goal: &S) -> Vec<S> {
+    let mut path = vec![goal.clone()];
+    let mut current = goal;
+    while let Some(parent) = parents.get(current) {
+        path.push(parent.clone());
+        current = parent;
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_support::{
+        property::{check, ints, vecs},
+        test_support::TestCase,
+    };
+
+    /// The edges of a small weighted graph, as (from, to, cost)
+    const EDGES: [(char, char, u32); 7] = [
+        ('a', 'b', 7),
+        ('a', 'c', 2),
+        ('c', 'b', 3),
+        ('b', 'd', 1),
+        ('c', 'd', 8),
+        ('d', 'e', 1),
+        ('f', 'a', 1),
+    ];
+
+    fn edges_from(state: &char) -> Vec<(char, u32)> {
+        EDGES
+            .iter()
+            .filter(|(from, _, _)| from == state)
+            .map(|&(_, to, cost)| (to, cost))
+            .collect()
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let test_cases = [
+            TestCase {
+                input: 'e',
+                expected: Some(Path {
+                    states: vec!['a', 'c', 'b', 'd', 'e'],
+                    cost: 7,
+                }),
+            },
+            TestCase {
+                input: 'a',
+                expected: Some(Path {
+                    states: vec!['a'],
+                    cost: 0,
+                }),
+            },
+            TestCase {
+                input: 'f',
+                expected: None,
+            },
+        ];
+        for TestCase { input, expected } in test_cases {
+            assert_eq!(expected, dijkstra(&'a', edges_from, |&s| s == input));
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let path = bfs(
+            &'a',
+            |state| edges_from(state).into_iter().map(|(to, _)| to),
+            |&s| s == 'e',
+        );
+        assert_eq!(
+            Some(Path {
+                states: vec!['a', 'b', 'd', 'e'],
+                cost: 3,
+            }),
+            path
+        );
+    }
+
+    #[test]
+    fn test_reconstruct_path() {
+        let parents = HashMap::from([(3, 2), (2, 1), (5, 1)]);
+        assert_eq!(vec![1, 2, 3], reconstruct_path(&parents, &3));
+        assert_eq!(vec![1, 5], reconstruct_path(&parents, &5));
+        assert_eq!(vec![1], reconstruct_path(&parents, &1));
+    }
+
+    #[test]
+    fn test_searches_agree_on_grids() {
+        // walking right or down across a grid of step costs from the top
+        // left to the bottom right; A* with the (admissible) number of steps
+        // left finds as cheap a path as Dijkstra, and with every step costing
+        // one, BFS finds a path as cheap as either
+        check(&vecs(ints(1..=9u32), 1..=25), |costs| {
+            let width = (costs.len() as f64).sqrt() as usize;
+            let goal = (width - 1, width - 1);
+            let successors = |&(row, col): &(usize, usize)| {
+                [(row + 1, col), (row, col + 1)]
+                    .into_iter()
+                    .filter(|&(row, col)| row < width && col < width)
+                    .map(|(row, col)| ((row, col), costs[row * width + col]))
+                    .collect::<Vec<_>>()
+            };
+            let steps_left = |&(row, col): &(usize, usize)| (2 * (width - 1) - row - col) as u32;
+
+            let shortest = dijkstra(&(0, 0), successors, |&s| s == goal).unwrap();
+            let estimated = astar(&(0, 0), successors, steps_left, |&s| s == goal).unwrap();
+            let fewest_steps = bfs(
+                &(0, 0),
+                |s| successors(s).into_iter().map(|(next, _)| next),
+                |&s| s == goal,
+            )
+            .unwrap();
+
+            shortest.cost == estimated.cost
+                && shortest.states.len() == 2 * width - 1
+                && fewest_steps.cost == 2 * (width - 1)
+                && shortest.cost
+                    == shortest.states[1..]
+                        .iter()
+                        .map(|&(row, col)| costs[row * width + col])
+                        .sum::<u32>()
+        });
+    }
+}
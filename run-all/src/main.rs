@@ -0,0 +1,176 @@
+mod runner;
+mod watchdog;
+
+use aoc::{get_solution, SOLUTIONS};
+use aoc_support::{
+    report::{Format, Printer},
+    solution::Solver,
+};
+use std::{env, num::NonZeroUsize, process, thread, time::Duration};
+
+const USAGE: &str = "usage: run-all [all | NN...] [--jobs N] [--timeout SECS]
+               [--format text|json|jsonl]";
+
+fn main() {
+    let args: Vec<_> = env::args().skip(1).collect();
+    let options = match Options::parse(&args) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("error: {}\n{}", message, USAGE);
+            process::exit(2);
+        }
+    };
+
+    watchdog::capture_panic_locations();
+    let mut all_ok = true;
+    let mut printer = Printer::new(options.format);
+    runner::run_days(&options.solvers, options.jobs, options.timeout, |report| {
+        all_ok &= report.is_ok();
+        printer.print(&report);
+    });
+    printer.finish();
+
+    if !all_ok {
+        process::exit(1);
+    }
+}
+
+struct Options {
+    solvers: Vec<&'static dyn Solver>,
+    /// The most days to run at once
+    jobs: usize,
+    /// How long each part can run before it is cancelled
+    timeout: Option<Duration>,
+    format: Format,
+}
+
+impl Options {
+    /// Pick out the solutions for the days named on the command line (every
+    /// day if there are none, or `all`), the number of jobs, the timeout and
+    /// the format
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut days = vec![];
+        let mut jobs = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+        let mut timeout = None;
+        let mut format = Format::default();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--jobs" => jobs = parse_jobs(args.next().map_or("", |a| a.as_str()))?,
+                arg if arg.starts_with("--jobs=") => jobs = parse_jobs(&arg["--jobs=".len()..])?,
+                "--timeout" => {
+                    timeout = Some(parse_timeout(args.next().map_or("", |a| a.as_str()))?)
+                }
+                arg if arg.starts_with("--timeout=") => {
+                    timeout = Some(parse_timeout(&arg["--timeout=".len()..])?)
+                }
+                "--format" => format = args.next().map_or("", |a| a.as_str()).parse()?,
+                arg if arg.starts_with("--format=") => format = arg["--format=".len()..].parse()?,
+                "all" => days.extend(SOLUTIONS.iter().map(|s| s.day())),
+                arg => match arg.parse::<u32>() {
+                    Ok(day) => days.push(day),
+                    Err(_) => return Err(format!("{} is not a day", arg)),
+                },
+            }
+        }
+
+        if days.is_empty() {
+            days.extend(SOLUTIONS.iter().map(|s| s.day()));
+        }
+
+        let solvers = days
+            .iter()
+            .map(|day| get_solution(*day).ok_or(format!("day {} has no solution", day)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            solvers,
+            jobs,
+            timeout,
+            format,
+        })
+    }
+}
+
+fn parse_jobs(arg: &str) -> Result<usize, String> {
+    match arg.parse::<usize>() {
+        Ok(jobs) if jobs > 0 => Ok(jobs),
+        _ => Err(String::from("--jobs needs a number of threads, at least 1")),
+    }
+}
+
+fn parse_timeout(arg: &str) -> Result<Duration, String> {
+    match arg.parse::<f64>() {
+        Ok(secs) if secs > 0.0 && secs.is_finite() => Ok(Duration::from_secs_f64(secs)),
+        _ => Err(String::from("--timeout needs a number of seconds")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_options(args: &str) -> Result<(Vec<u32>, usize, Format), String> {
+        let args: Vec<_> = args.split_ascii_whitespace().map(String::from).collect();
+        Options::parse(&args).map(|options| {
+            (
+                options.solvers.iter().map(|s| s.day()).collect(),
+                options.jobs,
+                options.format,
+            )
+        })
+    }
+
+    #[test]
+    fn test_parse_options() {
+        let all_days = vec![1, 2, 3, 4, 5, 6, 17];
+        let (days, jobs, format) = parse_options("").unwrap();
+        assert_eq!((all_days.clone(), Format::Text), (days, format));
+        assert!(jobs >= 1);
+
+        assert_eq!(
+            Ok((all_days.clone(), 2, Format::Text)),
+            parse_options("all --jobs 2")
+        );
+        assert_eq!(
+            Ok((vec![17, 3], 1, Format::JsonLines)),
+            parse_options("17 --jobs=1 03 --format jsonl")
+        );
+        assert_eq!(
+            Err(String::from("--jobs needs a number of threads, at least 1")),
+            parse_options("--jobs 0")
+        );
+        assert_eq!(
+            Err(String::from("--jobs needs a number of threads, at least 1")),
+            parse_options("--jobs")
+        );
+        assert_eq!(
+            Err(String::from("day 25 has no solution")),
+            parse_options("25")
+        );
+        assert_eq!(
+            Err(String::from("--runs is not a day")),
+            parse_options("--runs 3")
+        );
+    }
+
+    #[test]
+    fn test_parse_timeout_option() {
+        let timeout = |args: &str| {
+            let args: Vec<_> = args.split_ascii_whitespace().map(String::from).collect();
+            Options::parse(&args).map(|options| options.timeout)
+        };
+        assert_eq!(Ok(None), timeout("5"));
+        assert_eq!(Ok(Some(Duration::from_secs(30))), timeout("--timeout 30"));
+        assert_eq!(
+            Ok(Some(Duration::from_millis(500))),
+            timeout("17 --timeout=0.5")
+        );
+        for args in ["--timeout", "--timeout 0", "--timeout -1", "--timeout soon"] {
+            assert_eq!(
+                Err(String::from("--timeout needs a number of seconds")),
+                timeout(args)
+            );
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use crate::watchdog;
+use aoc_support::{
+    input,
+    report::DayReport,
+    solution::{format_day, Solver},
+};
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::Duration,
+};
+
+/// Load a day's puzzle input and solve it under a watchdog
+fn run_day(solver: &'static dyn Solver, timeout: Option<Duration>) -> DayReport {
+    match input::load(&format_day(solver.day()), solver.embedded_input()) {
+        Ok(input) => watchdog::solve(solver, input, timeout),
+        Err(e) => DayReport::failed(solver.day(), e.to_string()),
+    }
+}
+
+/// Run the days on up to `jobs` threads, giving each part up to `timeout`,
+/// and hand each day's report to `report` in the order of `solvers`
+pub fn run_days(
+    solvers: &[&'static dyn Solver],
+    jobs: usize,
+    timeout: Option<Duration>,
+    report: impl FnMut(DayReport),
+) {
+    run_in_parallel(solvers, jobs, |solver| run_day(*solver, timeout), report);
+}
+
+/// Apply `work` to each of the items on up to `jobs` threads. Each result is
+/// handed to `done` in the order of the items, as soon as it and the results
+/// before it are ready.
+fn run_in_parallel<T: Sync, R: Send>(
+    items: &[T],
+    jobs: usize,
+    work: impl Fn(&T) -> R + Sync,
+    mut done: impl FnMut(R),
+) {
+    let next_item = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            let sender = sender.clone();
+            let (next_item, work) = (&next_item, &work);
+            scope.spawn(move || loop {
+                let index = next_item.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+                // the receiver is only dropped once every result is in
+                sender.send((index, work(item))).unwrap();
+            });
+        }
+        drop(sender);
+
+        // results that are ready before some of those in front of them
+        let mut waiting: Vec<Option<R>> = items.iter().map(|_| None).collect();
+        let mut next_done = 0;
+        for (index, result) in receiver {
+            waiting[index] = Some(result);
+            while let Some(result) = waiting.get_mut(next_done).and_then(Option::take) {
+                done(result);
+                next_done += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_in_parallel() {
+        let running = AtomicUsize::new(0);
+        let most_running = AtomicUsize::new(0);
+        let mut results = vec![];
+        let items: Vec<u64> = (0..12).collect();
+        run_in_parallel(
+            &items,
+            3,
+            |&item| {
+                let now_running = running.fetch_add(1, Ordering::SeqCst) + 1;
+                most_running.fetch_max(now_running, Ordering::SeqCst);
+                // later items finish first, so the results arrive out of order
+                thread::sleep(Duration::from_millis(12 - item));
+                running.fetch_sub(1, Ordering::SeqCst);
+                item * 10
+            },
+            |result| results.push(result),
+        );
+        assert_eq!(
+            items.iter().map(|item| item * 10).collect::<Vec<_>>(),
+            results
+        );
+        assert!(most_running.load(Ordering::SeqCst) <= 3);
+
+        let mut results = vec![];
+        run_in_parallel(
+            &[] as &[u64],
+            4,
+            |&item| item,
+            |result| results.push(result),
+        );
+        assert!(results.is_empty());
+    }
+}
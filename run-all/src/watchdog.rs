@@ -0,0 +1,325 @@
+use aoc_support::{
+    cancel::{CancellationToken, Cancelled},
+    report::{DayAnswers, DayReport, PartReport},
+    solution::{format_day, Solver},
+};
+use std::{
+    any::Any,
+    cell::RefCell,
+    panic::{self, AssertUnwindSafe},
+    sync::mpsc::{self, RecvTimeoutError, SendError, Sender},
+    thread,
+    time::{Duration, Instant},
+};
+
+thread_local! {
+    /// Where the last panic on this thread happened, if the hook installed by
+    /// `capture_panic_locations` is recording them
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Replace the panic hook, which prints every panic as it happens, with one
+/// that quietly records where it happened, so that the failure can say
+pub fn capture_panic_locations() {
+    panic::set_hook(Box::new(|info| {
+        let location = info.location().map(|l| l.to_string());
+        PANIC_LOCATION.set(location);
+    }));
+}
+
+/// The message given to `panic!`, which is a `&str` or a `String` unless
+/// the panic was raised with some other payload
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "(no message)"
+    }
+}
+
+/// Run `f` with `token` as the thread's cancellation token, turning a panic,
+/// or `f` stopping because it was cancelled, into an error
+fn isolate<T>(token: &CancellationToken, f: impl FnOnce() -> T) -> Result<T, String> {
+    PANIC_LOCATION.take();
+    panic::catch_unwind(AssertUnwindSafe(|| token.run(f))).map_err(|payload| {
+        if payload.is::<Cancelled>() {
+            return String::from("cancelled");
+        }
+        let message = panic_message(payload.as_ref());
+        match PANIC_LOCATION.take() {
+            Some(location) => format!("panicked at {}: {}", location, message),
+            None => format!("panicked: {}", message),
+        }
+    })
+}
+
+/// What the thread solving a day tells its watchdog
+enum Event {
+    /// A phase has started: parsing (phase 0) or a part. It can be cancelled
+    /// with the token.
+    Started(u32, CancellationToken),
+    /// The input was parsed in the given time, or couldn't be
+    Parsed(Result<Duration, String>),
+    /// A part has been solved, or failed
+    Solved(PartReport),
+}
+
+/// Parse `input` and solve both parts on a thread of their own, with this
+/// thread as the watchdog. Each phase (parsing, then each part) has up to
+/// `timeout` to finish. One that doesn't is cancelled and reported as a
+/// `TIMEOUT`, and the watchdog doesn't wait for it: the solving thread stops
+/// at its next cancellation check, if it makes one, and the next part then
+/// has as long again. A panic fails only the phase it happened in.
+pub fn solve(solver: &'static dyn Solver, input: String, timeout: Option<Duration>) -> DayReport {
+    let day = solver.day();
+    let (sender, receiver) = mpsc::channel();
+    thread::Builder::new()
+        .name(format!("day {}", format_day(day)))
+        .spawn(move || solve_phases(solver, &input, &sender))
+        .expect("couldn't start a thread to solve the day");
+
+    let mut parse = None;
+    let mut parts = vec![];
+    let mut running = None;
+    let mut deadline = timeout.map(|timeout| Instant::now() + timeout);
+    while parts.len() < 2 {
+        let event = match deadline {
+            Some(deadline) => {
+                receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+            }
+            None => receiver.recv().map_err(RecvTimeoutError::from),
+        };
+        // the phase being waited for, which is behind the solving thread if
+        // it is still stopping a part that timed out
+        let waiting_for = if parse.is_none() {
+            0
+        } else {
+            parts.len() as u32 + 1
+        };
+
+        match event {
+            Ok(Event::Started(phase, token)) if phase == waiting_for => {
+                deadline = timeout.map(|timeout| Instant::now() + timeout);
+                running = Some(token);
+            }
+            Ok(Event::Started(..)) => (),
+            Ok(Event::Parsed(Ok(elapsed))) => parse = Some(elapsed),
+            Ok(Event::Parsed(Err(error))) => return DayReport::failed(day, error),
+            Ok(Event::Solved(part)) if part.part == waiting_for => parts.push(part),
+            Ok(Event::Solved(_)) => (),
+            Err(RecvTimeoutError::Timeout) => {
+                if let Some(token) = running.take() {
+                    token.cancel();
+                }
+                // there is only a deadline to miss when there is a timeout
+                let timeout = timeout.unwrap();
+                let error = format!("TIMEOUT after {:?}", timeout);
+                if parse.is_none() {
+                    return DayReport::failed(day, format!("{} parsing the puzzle input", error));
+                }
+                parts.push(PartReport {
+                    part: waiting_for,
+                    answer: Err(error),
+                    elapsed: timeout,
+                });
+                deadline = Some(Instant::now() + timeout);
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                return DayReport::failed(day, String::from("the day stopped without answers"))
+            }
+        }
+    }
+
+    DayReport {
+        day,
+        result: Ok(DayAnswers {
+            parse: parse.unwrap(),
+            parts,
+        }),
+    }
+}
+
+/// Parse the input and solve each part, telling the watchdog as each phase
+/// starts and ends. Gives up once the watchdog has stopped listening.
+fn solve_phases(
+    solver: &dyn Solver,
+    input: &str,
+    events: &Sender<Event>,
+) -> Result<(), SendError<Event>> {
+    let token = CancellationToken::new();
+    events.send(Event::Started(0, token.clone()))?;
+    let start = Instant::now();
+    let parsed_input = match isolate(&token, || solver.parse(input)) {
+        Ok(Ok(parsed_input)) => parsed_input,
+        Ok(Err(e)) => {
+            let error = format!("could not parse the puzzle input: {}", e);
+            return events.send(Event::Parsed(Err(error)));
+        }
+        Err(error) => return events.send(Event::Parsed(Err(error))),
+    };
+    events.send(Event::Parsed(Ok(start.elapsed())))?;
+
+    for part in 1..=2 {
+        let token = CancellationToken::new();
+        events.send(Event::Started(part, token.clone()))?;
+        let start = Instant::now();
+        let answer = isolate(&token, || match part {
+            1 => solver.part1(parsed_input.as_ref()),
+            _ => solver.part2(parsed_input.as_ref()),
+        })
+        .and_then(|answer| answer.map_err(|e| e.to_string()));
+        events.send(Event::Solved(PartReport {
+            part,
+            answer,
+            elapsed: start.elapsed(),
+        }))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_support::{
+        answer::Answer,
+        cancel,
+        parse::{parse_number, ParseError},
+        solution::{Solution, SolveError},
+    };
+    use test_support::test_support::{run_tests, TestCase};
+
+    /// A day whose input is a number, which part 1 can't solve if it's
+    /// negative and part 2 can't solve if it's too big to double
+    struct Fragile;
+
+    impl Solution for Fragile {
+        type Input = i64;
+
+        fn day(&self) -> u32 {
+            3
+        }
+
+        fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+            parse_number(input, input.trim())
+        }
+
+        fn part1(&self, number: &Self::Input) -> Result<Answer, SolveError> {
+            assert!(*number >= 0, "{} is negative", number);
+            Ok((*number).into())
+        }
+
+        fn part2(&self, number: &Self::Input) -> Result<Answer, SolveError> {
+            number
+                .checked_mul(2)
+                .map(Answer::from)
+                .ok_or(SolveError::new("twice the number doesn't fit in an i64"))
+        }
+    }
+
+    /// A day whose part 1 never finishes. It stops when cancelled if its
+    /// input is "cooperative", and otherwise sleeps for a while first.
+    struct Endless;
+
+    impl Solution for Endless {
+        type Input = bool;
+
+        fn day(&self) -> u32 {
+            9
+        }
+
+        fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+            Ok(input == "cooperative")
+        }
+
+        fn part1(&self, cooperative: &Self::Input) -> Result<Answer, SolveError> {
+            loop {
+                if *cooperative {
+                    cancel::check();
+                } else {
+                    thread::sleep(Duration::from_millis(10));
+                }
+            }
+        }
+
+        fn part2(&self, _: &Self::Input) -> Result<Answer, SolveError> {
+            Ok(2.into())
+        }
+    }
+
+    /// The answers (or errors) in a day's report
+    fn answers(report: DayReport) -> Result<Vec<Result<Answer, String>>, String> {
+        report
+            .result
+            .map(|answers| answers.parts.into_iter().map(|part| part.answer).collect())
+    }
+
+    #[test]
+    fn test_solve() {
+        let test_cases = [
+            TestCase {
+                input: "21",
+                expected: Ok(vec![Ok(Answer::from(21)), Ok(Answer::from(42))]),
+            },
+            // only the part that panics fails
+            TestCase {
+                input: "-21",
+                expected: Ok(vec![
+                    Err(String::from("panicked: -21 is negative")),
+                    Ok(Answer::from(-42)),
+                ]),
+            },
+            TestCase {
+                input: "9223372036854775807",
+                expected: Ok(vec![
+                    Ok(Answer::from(i64::MAX)),
+                    Err(String::from("twice the number doesn't fit in an i64")),
+                ]),
+            },
+            TestCase {
+                input: "x",
+                expected: Err(String::from(
+                    "could not parse the puzzle input: line 1, column 1: expected a number, found \"x\"",
+                )),
+            },
+        ];
+        run_tests(
+            |input| answers(solve(&Fragile, String::from(*input), None)),
+            &test_cases,
+        );
+        assert!(solve(&Fragile, String::from("21"), Some(Duration::from_secs(60))).is_ok());
+    }
+
+    #[test]
+    fn test_solve_with_timeout() {
+        let timeout = Duration::from_millis(50);
+        let timed_out = || Err(String::from("TIMEOUT after 50ms"));
+
+        // part 1 stops when it is cancelled, so part 2 still gets solved
+        assert_eq!(
+            Ok(vec![timed_out(), Ok(Answer::from(2))]),
+            answers(solve(&Endless, String::from("cooperative"), Some(timeout)))
+        );
+
+        // part 1 never stops, so part 2 never starts, but the watchdog
+        // doesn't wait for it any longer than the timeout
+        let start = Instant::now();
+        assert_eq!(
+            Ok(vec![timed_out(), timed_out()]),
+            answers(solve(&Endless, String::from("stubborn"), Some(timeout)))
+        );
+        assert!(start.elapsed() < 4 * timeout);
+    }
+
+    #[test]
+    fn test_panic_message() {
+        let message = |f: fn()| {
+            let payload = panic::catch_unwind(f).unwrap_err();
+            String::from(panic_message(payload.as_ref()))
+        };
+        assert_eq!("literal", message(|| panic!("literal")));
+        assert_eq!("formatted 7", message(|| panic!("formatted {}", 7)));
+        assert_eq!("(no message)", message(|| panic::panic_any(7)));
+    }
+}
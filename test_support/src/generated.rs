@@ -0,0 +1,89 @@
+use aoc_support::{
+    answer::Answer,
+    solution::{Solution, SolveError},
+};
+
+/// Check a day's input generator on the first ten seeds: each seed gives the
+/// same input every time, and the input parses and both parts can be solved.
+/// `check` is handed what each input parsed to and the answers to both
+/// parts, to check that they are what the generator set out to make.
+pub fn check_generated_inputs<S: Solution>(
+    solution: &S,
+    size: usize,
+    check: impl Fn(&S::Input, Answer, Answer),
+) {
+    for seed in 0..10 {
+        let input = solution
+            .generate_input(seed, size)
+            .expect("the day has no input generator");
+        assert_eq!(
+            Some(&input),
+            solution.generate_input(seed, size).as_ref(),
+            "seed {} gave a different input the second time",
+            seed
+        );
+        let parsed = solution
+            .parse(&input)
+            .unwrap_or_else(|e| panic!("the input for seed {} doesn't parse: {}", seed, e));
+        let solve = |part, answer: Result<Answer, SolveError>| {
+            answer.unwrap_or_else(|e| panic!("part {} of the input for seed {}: {}", part, seed, e))
+        };
+        check(
+            &parsed,
+            solve(1, solution.part1(&parsed)),
+            solve(2, solution.part2(&parsed)),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_support::parse::{parse_number, ParseError};
+    use std::{cell::Cell, panic};
+
+    /// A day whose input is a number, made from the seed, or from the size
+    /// too if it is odd, which spoils the input for seed 3
+    struct Doubling;
+
+    impl Solution for Doubling {
+        type Input = u64;
+
+        fn day(&self) -> u32 {
+            1
+        }
+
+        fn generate_input(&self, seed: u64, size: usize) -> Option<String> {
+            match (seed, size % 2) {
+                (3, 1) => Some(String::from("three")),
+                _ => Some(seed.to_string()),
+            }
+        }
+
+        fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+            parse_number(input, input)
+        }
+
+        fn part1(&self, number: &Self::Input) -> Result<Answer, SolveError> {
+            Ok((*number).into())
+        }
+
+        fn part2(&self, number: &Self::Input) -> Result<Answer, SolveError> {
+            Ok((number * 2).into())
+        }
+    }
+
+    #[test]
+    fn test_check_generated_inputs() {
+        let checked = Cell::new(0);
+        check_generated_inputs(&Doubling, 2, |number, part1, part2| {
+            assert_eq!(Answer::from(*number), part1);
+            assert_eq!(Answer::from(number * 2), part2);
+            checked.set(checked.get() + 1);
+        });
+        assert_eq!(10, checked.get());
+
+        let result = panic::catch_unwind(|| check_generated_inputs(&Doubling, 1, |_, _, _| ()));
+        assert!(result.is_err());
+    }
+}
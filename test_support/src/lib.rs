@@ -1,16 +1,293 @@
+pub mod generated;
+pub mod property;
+pub mod snapshot;
+
 pub mod test_support {
+    use std::{
+        any::Any,
+        fmt::{Debug, Write},
+        panic::{self, AssertUnwindSafe},
+    };
 
     pub struct TestCase<I, E> {
         pub input: I,
         pub expected: E,
     }
 
-    pub fn run_tests<I: Copy, E: std::fmt::Debug + std::cmp::PartialEq>(
-        fn_under_test: fn(input: I) -> E,
-        test_cases: &[TestCase<I, E>],
-    ) {
-        for TestCase { input, expected } in test_cases.iter() {
-            assert_eq!(fn_under_test(*input), *expected);
+    /// Remove the indentation from an input embedded in a test, so that it
+    /// reads like a puzzle input. The first line is the one that follows the
+    /// opening quote, so only the lines after it set how much indentation
+    /// to remove, unless it is empty, in which case it is dropped. Like a
+    /// normalized puzzle input, the result has no trailing whitespace and
+    /// ends with a newline.
+    ///
+    /// ```ignore
+    /// const TEST_INPUT: &str = r"Card 1: 41 48 | 83 86
+    ///     Card 2: 13 32 | 61 30";
+    ///
+    /// assert_eq!("Card 1: 41 48 | 83 86\nCard 2: 13 32 | 61 30\n", dedent(TEST_INPUT));
+    /// ```
+    pub fn dedent(text: &str) -> String {
+        let mut lines: Vec<&str> = text.lines().map(str::trim_end).collect();
+        let indented = match lines.first() {
+            Some(&"") => {
+                lines.remove(0);
+                &lines[..]
+            }
+            Some(_) => &lines[1..],
+            None => &lines[..],
+        };
+        let indent = indented
+            .iter()
+            .filter(|line| !line.is_empty())
+            .map(|line| line.len() - line.trim_start().len())
+            .min()
+            .unwrap_or(0);
+
+        let mut dedented = String::new();
+        for (index, line) in lines.iter().enumerate() {
+            let is_first_line = index == 0 && indented.len() < lines.len();
+            dedented.push_str(if is_first_line || line.is_empty() {
+                line
+            } else {
+                &line[indent..]
+            });
+            dedented.push('\n');
+        }
+        dedented.truncate(dedented.trim_end().len());
+        if !dedented.is_empty() {
+            dedented.push('\n');
+        }
+        dedented
+    }
+
+    /// Inputs and answers longer than this are cut short in the failure
+    /// table, so that a whole puzzle input doesn't swamp it
+    const MAX_CELL_WIDTH: usize = 40;
+
+    /// Run `fn_under_test` on every case's input, then fail once with a table
+    /// of every case whose result wasn't what was expected (or that
+    /// panicked). The function can be a closure, so methods can be tested:
+    ///
+    /// ```ignore
+    /// run_tests(|location| schematic.is_gear(location), &test_cases);
+    /// ```
+    pub fn run_tests<I, E, A>(fn_under_test: impl Fn(&I) -> A, test_cases: &[TestCase<I, E>])
+    where
+        I: Debug,
+        E: Debug,
+        A: Debug + PartialEq<E>,
+    {
+        let mut failures = vec![];
+        for (index, TestCase { input, expected }) in test_cases.iter().enumerate() {
+            let actual = match panic::catch_unwind(AssertUnwindSafe(|| fn_under_test(input))) {
+                Ok(actual) if actual == *expected => continue,
+                Ok(actual) => format!("{:?}", actual),
+                Err(payload) => format!("panicked: {}", get_panic_message(&*payload)),
+            };
+            failures.push([
+                index.to_string(),
+                format!("{:?}", input),
+                format!("{:?}", expected),
+                actual,
+            ]);
+        }
+
+        if !failures.is_empty() {
+            panic!(
+                "{} of {} test cases failed\n{}",
+                failures.len(),
+                test_cases.len(),
+                format_table(["case", "input", "expected", "actual"], &failures)
+            );
+        }
+    }
+
+    fn get_panic_message(payload: &(dyn Any + Send)) -> &str {
+        if let Some(message) = payload.downcast_ref::<&str>() {
+            message
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message
+        } else {
+            "(no message)"
+        }
+    }
+
+    fn format_table(header: [&str; 4], rows: &[[String; 4]]) -> String {
+        let rows: Vec<[String; 4]> = std::iter::once(header.map(String::from))
+            .chain(
+                rows.iter()
+                    .map(|row| row.clone().map(|cell| shorten(&cell))),
+            )
+            .collect();
+        let widths: Vec<usize> = (0..4)
+            .map(|column| {
+                rows.iter()
+                    .map(|row| row[column].chars().count())
+                    .max()
+                    .unwrap()
+            })
+            .collect();
+
+        let mut table = String::new();
+        for row in rows.iter() {
+            let cells: Vec<String> = row
+                .iter()
+                .zip(widths.iter())
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .collect();
+            writeln!(table, "{}", cells.join(" | ").trim_end()).unwrap();
         }
+        table
+    }
+
+    fn shorten(cell: &str) -> String {
+        if cell.chars().count() <= MAX_CELL_WIDTH {
+            String::from(cell)
+        } else {
+            let mut short: String = cell.chars().take(MAX_CELL_WIDTH - 3).collect();
+            short.push_str("...");
+            short
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_dedent() {
+            let test_cases = [
+                TestCase {
+                    input: "Card 1: 41 48 | 83 86\n    Card 2: 13 32 | 61 30",
+                    expected: "Card 1: 41 48 | 83 86\nCard 2: 13 32 | 61 30\n",
+                },
+                TestCase {
+                    input: "seeds: 79 14\n\n    seed-to-soil map:\n    50 98 2\n    \n    soil-to-fertilizer map:\n      0 15 37\n    ",
+                    expected: "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n\nsoil-to-fertilizer map:\n  0 15 37\n",
+                },
+                TestCase {
+                    input: "\n        .#.\n        #..\n    ",
+                    expected: ".#.\n#..\n",
+                },
+                TestCase {
+                    input: "1abc2",
+                    expected: "1abc2\n",
+                },
+                TestCase {
+                    input: "",
+                    expected: "",
+                },
+            ];
+            run_tests(|input| dedent(input), &test_cases);
+        }
+
+        #[test]
+        fn test_run_tests() {
+            let test_cases = [
+                TestCase {
+                    input: String::from("abc"),
+                    expected: 3,
+                },
+                TestCase {
+                    input: String::new(),
+                    expected: 0,
+                },
+            ];
+            run_tests(|input| input.len(), &test_cases);
+        }
+
+        #[test]
+        fn test_run_tests_reports_every_failure() {
+            let test_cases = [
+                TestCase {
+                    input: "1",
+                    expected: 1,
+                },
+                TestCase {
+                    input: "2",
+                    expected: 3,
+                },
+                TestCase {
+                    input: "x",
+                    expected: 0,
+                },
+                TestCase {
+                    input: "a very long input that goes on for much too long",
+                    expected: 4,
+                },
+            ];
+            let result = panic::catch_unwind(|| {
+                run_tests(|input| input.parse::<i32>().unwrap(), &test_cases)
+            });
+            let message = get_panic_message(&*result.unwrap_err()).to_string();
+            assert_eq!(
+                "3 of 4 test cases failed\n\
+                 case | input                                    | expected | actual\n\
+                 1    | \"2\"                                      | 3        | 2\n\
+                 2    | \"x\"                                      | 0        | panicked: called `Result::unwrap()` o...\n\
+                 3    | \"a very long input that goes on for m... | 4        | panicked: called `Result::unwrap()` o...\n",
+                message
+            );
+        }
+    }
+}
+
+/// Turn a named list of `TestCase`s into a module of `#[test]` functions,
+/// one per case, so each case passes or fails on its own and shows up by
+/// name in the `cargo test` output:
+///
+/// ```ignore
+/// test_cases! {
+///     test_get_digits => get_digits,
+///     one_digit: TestCase { input: "treb7uchet", expected: vec![7] },
+///     #[should_panic]
+///     no_digits: TestCase { input: "trebuchet", expected: vec![] },
+/// }
+/// ```
+///
+/// runs `tests::test_get_digits::one_digit` and
+/// `tests::test_get_digits::no_digits`, each checking that the function (or
+/// closure) returns `expected` when given `input`. Attributes before a case
+/// are put on its test.
+#[macro_export]
+macro_rules! test_cases {
+    ($group:ident => $fn_under_test:expr, $($(#[$attr:meta])* $name:ident: $case:expr),+ $(,)?) => {
+        mod $group {
+            #[allow(unused_imports)]
+            use super::*;
+
+            $(
+                #[test]
+                $(#[$attr])*
+                fn $name() {
+                    let $crate::test_support::TestCase { input, expected } = $case;
+                    assert_eq!(($fn_under_test)(input), expected);
+                }
+            )+
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_support::TestCase;
+
+    fn double(n: i32) -> i32 {
+        n * 2
+    }
+
+    crate::test_cases! {
+        test_double => double,
+        zero: TestCase { input: 0, expected: 0 },
+        negative: TestCase { input: -3, expected: -6 },
+        #[should_panic]
+        wrong: TestCase { input: 1, expected: 3 },
+    }
+
+    crate::test_cases! {
+        test_closure => |input: &str| input.len(),
+        empty: TestCase { input: "", expected: 0 },
+        word: TestCase { input: "trebuchet", expected: 9 },
     }
 }
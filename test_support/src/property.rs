@@ -0,0 +1,536 @@
+pub use aoc_support::rng::{Int, Rng};
+use std::{
+    cell::Cell,
+    env,
+    fmt::Debug,
+    ops::RangeInclusive,
+    panic::{self, AssertUnwindSafe},
+    str::FromStr,
+    sync::Once,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// The environment variable that sets the seed, to rerun a failed check
+pub const SEED_ENV: &str = "PROPERTY_SEED";
+/// The environment variable that sets how many cases each check tries
+pub const CASES_ENV: &str = "PROPERTY_CASES";
+
+const DEFAULT_CASES: u32 = 100;
+const MAX_SHRINKS: u32 = 1000;
+
+thread_local! {
+    /// Whether the panic hook installed by `quietly` should keep quiet
+    /// about panics on this thread
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Generates random values of a type, and simpler versions of a value that
+/// made a property fail
+pub trait Strategy {
+    type Value: Clone + Debug;
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value;
+
+    /// Values that are simpler than `value`, simplest first. A failing
+    /// value is shrunk by moving to the first of these that still fails,
+    /// until none do.
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value>;
+}
+
+/// A value in `low..=high` that is more likely than chance to be one of the
+/// ends of the range, where the bugs tend to be
+fn in_range_or_edge(rng: &mut Rng, low: i128, high: i128) -> i128 {
+    match rng.next_u64() % 8 {
+        0 => low,
+        1 => high,
+        _ => rng.range(low..=high),
+    }
+}
+
+/// Integers in `range`, shrinking towards zero (or the end of the range
+/// nearest to it)
+pub fn ints<T: Int>(range: RangeInclusive<T>) -> Ints<T> {
+    Ints { range }
+}
+
+pub struct Ints<T> {
+    range: RangeInclusive<T>,
+}
+
+impl<T: Int> Ints<T> {
+    fn bounds(&self) -> (i128, i128) {
+        (self.range.start().to_i128(), self.range.end().to_i128())
+    }
+}
+
+impl<T: Int> Strategy for Ints<T> {
+    type Value = T;
+
+    fn generate(&self, rng: &mut Rng) -> T {
+        let (low, high) = self.bounds();
+        T::from_i128(in_range_or_edge(rng, low, high))
+    }
+
+    fn shrink(&self, value: &T) -> Vec<T> {
+        let (low, high) = self.bounds();
+        let value = value.to_i128();
+        let target = 0.clamp(low, high);
+        let mut candidates = vec![target, value - (value - target) / 2];
+        candidates.push(value - (value - target).signum());
+        candidates.dedup();
+        candidates
+            .into_iter()
+            .filter(|&c| c != value)
+            .map(T::from_i128)
+            .collect()
+    }
+}
+
+/// Strings of characters from `alphabet`, with a length in `len`. They
+/// shrink by getting shorter and by using the alphabet's first character.
+pub fn strings(alphabet: &str, len: RangeInclusive<usize>) -> Strings {
+    assert!(!alphabet.is_empty(), "the alphabet is empty");
+    Strings {
+        alphabet: alphabet.chars().collect(),
+        len,
+    }
+}
+
+pub struct Strings {
+    alphabet: Vec<char>,
+    len: RangeInclusive<usize>,
+}
+
+impl Strategy for Strings {
+    type Value = String;
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        let len = ints(self.len.clone()).generate(rng);
+        (0..len)
+            .map(|_| self.alphabet[rng.index(self.alphabet.len())])
+            .collect()
+    }
+
+    fn shrink(&self, value: &String) -> Vec<String> {
+        let chars: Vec<char> = value.chars().collect();
+        let simplest = self.alphabet[0];
+        let shorter = shrink_len(&chars, *self.len.start());
+        let simpler = (0..chars.len())
+            .filter(|&i| chars[i] != simplest)
+            .map(|i| replace(&chars, i, simplest));
+        shorter
+            .into_iter()
+            .chain(simpler)
+            .map(|chars| chars.into_iter().collect())
+            .collect()
+    }
+}
+
+/// Vectors of values from `element`, with a length in `len`. They shrink by
+/// getting shorter and by shrinking their elements.
+pub fn vecs<S: Strategy>(element: S, len: RangeInclusive<usize>) -> Vecs<S> {
+    Vecs { element, len }
+}
+
+pub struct Vecs<S> {
+    element: S,
+    len: RangeInclusive<usize>,
+}
+
+impl<S: Strategy> Strategy for Vecs<S> {
+    type Value = Vec<S::Value>;
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value {
+        let len = ints(self.len.clone()).generate(rng);
+        (0..len).map(|_| self.element.generate(rng)).collect()
+    }
+
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value> {
+        let mut candidates = shrink_len(value, *self.len.start());
+        for (index, element) in value.iter().enumerate() {
+            for simpler in self.element.shrink(element) {
+                candidates.push(replace(value, index, simpler));
+            }
+        }
+        candidates
+    }
+}
+
+/// Rectangular grids of characters from `alphabet`, as puzzle input text:
+/// rows of equal length separated by newlines. They shrink by losing rows
+/// and columns and by using the alphabet's first character.
+pub fn grids(alphabet: &str, rows: RangeInclusive<usize>, columns: RangeInclusive<usize>) -> Grids {
+    assert!(!alphabet.is_empty(), "the alphabet is empty");
+    Grids {
+        alphabet: alphabet.chars().collect(),
+        rows,
+        columns,
+    }
+}
+
+pub struct Grids {
+    alphabet: Vec<char>,
+    rows: RangeInclusive<usize>,
+    columns: RangeInclusive<usize>,
+}
+
+impl Strategy for Grids {
+    type Value = String;
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        let rows = ints(self.rows.clone()).generate(rng);
+        let columns = ints(self.columns.clone()).generate(rng);
+        (0..rows)
+            .map(|_| {
+                (0..columns)
+                    .map(|_| self.alphabet[rng.index(self.alphabet.len())])
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn shrink(&self, value: &String) -> Vec<String> {
+        let grid: Vec<Vec<char>> = value.lines().map(|l| l.chars().collect()).collect();
+        let columns = grid.first().map_or(0, |row| row.len());
+        let to_text = |grid: Vec<Vec<char>>| {
+            grid.iter()
+                .map(|row| row.iter().collect::<String>())
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+
+        let mut candidates = vec![];
+        if grid.len() > *self.rows.start() {
+            candidates.push(grid[..grid.len() - 1].to_vec());
+        }
+        if columns > *self.columns.start() {
+            candidates.push(grid.iter().map(|row| row[..columns - 1].to_vec()).collect());
+        }
+        let simplest = self.alphabet[0];
+        for (r, row) in grid.iter().enumerate() {
+            for (c, &cell) in row.iter().enumerate() {
+                if cell != simplest {
+                    candidates.push(replace(&grid, r, replace(row, c, simplest)));
+                }
+            }
+        }
+        candidates.into_iter().map(to_text).collect()
+    }
+}
+
+impl<A: Strategy, B: Strategy> Strategy for (A, B) {
+    type Value = (A::Value, B::Value);
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value {
+        (self.0.generate(rng), self.1.generate(rng))
+    }
+
+    fn shrink(&self, (a, b): &Self::Value) -> Vec<Self::Value> {
+        let shrink_a = self.0.shrink(a).into_iter().map(|a| (a, b.clone()));
+        let shrink_b = self.1.shrink(b).into_iter().map(|b| (a.clone(), b));
+        shrink_a.chain(shrink_b).collect()
+    }
+}
+
+impl<A: Strategy, B: Strategy, C: Strategy> Strategy for (A, B, C) {
+    type Value = (A::Value, B::Value, C::Value);
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value {
+        (
+            self.0.generate(rng),
+            self.1.generate(rng),
+            self.2.generate(rng),
+        )
+    }
+
+    fn shrink(&self, (a, b, c): &Self::Value) -> Vec<Self::Value> {
+        let shrink_a = self
+            .0
+            .shrink(a)
+            .into_iter()
+            .map(|a| (a, b.clone(), c.clone()));
+        let shrink_b = self
+            .1
+            .shrink(b)
+            .into_iter()
+            .map(|b| (a.clone(), b, c.clone()));
+        let shrink_c = self
+            .2
+            .shrink(c)
+            .into_iter()
+            .map(|c| (a.clone(), b.clone(), c));
+        shrink_a.chain(shrink_b).chain(shrink_c).collect()
+    }
+}
+
+/// Shorter versions of `items`, no shorter than `min_len`: without its
+/// first or second half, then without each item in turn
+fn shrink_len<T: Clone>(items: &[T], min_len: usize) -> Vec<Vec<T>> {
+    let len = items.len();
+    if len <= min_len {
+        return vec![];
+    }
+    let mut candidates = vec![];
+    let half = len / 2;
+    if half > 0 && len - half >= min_len {
+        candidates.push(items[half..].to_vec());
+        candidates.push(items[..len - half].to_vec());
+    }
+    for index in 0..len {
+        let mut shorter = items.to_vec();
+        shorter.remove(index);
+        candidates.push(shorter);
+    }
+    candidates
+}
+
+fn replace<T: Clone>(items: &[T], index: usize, item: T) -> Vec<T> {
+    let mut replaced = items.to_vec();
+    replaced[index] = item;
+    replaced
+}
+
+/// How a property is checked
+#[derive(Debug, Clone, Copy)]
+pub struct Config {
+    pub cases: u32,
+    pub seed: u64,
+}
+
+impl Default for Config {
+    /// 100 cases (or `PROPERTY_CASES`) from a seed that differs from run to
+    /// run (or `PROPERTY_SEED`)
+    fn default() -> Self {
+        Self {
+            cases: from_env(CASES_ENV).unwrap_or(DEFAULT_CASES),
+            seed: from_env(SEED_ENV).unwrap_or_else(|| {
+                SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |t| t.as_nanos() as u64)
+            }),
+        }
+    }
+}
+
+/// The value of the environment variable `name`, if it is set. Panics if it
+/// can't be parsed, rather than quietly checking something else.
+fn from_env<T: FromStr>(name: &str) -> Option<T> {
+    let value = env::var(name).ok()?;
+    let parsed = value
+        .parse()
+        .unwrap_or_else(|_| panic!("{}={} is out of range or not a number", name, value));
+    Some(parsed)
+}
+
+/// A value that made a property fail
+#[derive(Debug, PartialEq)]
+pub struct Failure<V> {
+    /// Which case failed, counting from 1
+    pub case: u32,
+    pub original: V,
+    /// The simplest value found by shrinking `original` that still fails
+    pub shrunk: V,
+    pub shrinks: u32,
+}
+
+/// Check that `property` holds for values generated by `strategy`, and
+/// panic with the simplest failing value and the seed to rerun with if it
+/// doesn't. A property fails if it returns false or panics.
+///
+/// ```ignore
+/// check(&(ints(0..=100), ints(0..=100)), |&(a, b)| a + b >= a);
+/// ```
+pub fn check<S: Strategy>(strategy: &S, property: impl Fn(&S::Value) -> bool) {
+    check_with(Config::default(), strategy, property)
+}
+
+pub fn check_with<S: Strategy>(config: Config, strategy: &S, property: impl Fn(&S::Value) -> bool) {
+    if let Some(failure) = find_failure(config, strategy, &property) {
+        // the panics while shrinking weren't printed, so if the property
+        // panics, show where for the simplest value alone
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| property(&failure.shrunk)));
+        panic!(
+            "property failed on case {} of {} (seed {}; rerun with {}={})\n  \
+             input:  {:?}\n  \
+             shrunk: {:?} (after {} shrinks)",
+            failure.case,
+            config.cases,
+            config.seed,
+            SEED_ENV,
+            config.seed,
+            failure.original,
+            failure.shrunk,
+            failure.shrinks
+        );
+    }
+}
+
+/// Find the first generated value that makes `property` fail, and shrink
+/// it. Panics in the property are caught without being printed, so that
+/// shrinking doesn't print one for every simpler value it tries.
+pub fn find_failure<S: Strategy>(
+    config: Config,
+    strategy: &S,
+    property: impl Fn(&S::Value) -> bool,
+) -> Option<Failure<S::Value>> {
+    let fails = |value: &S::Value| {
+        quietly(|| !panic::catch_unwind(AssertUnwindSafe(|| property(value))).unwrap_or(false))
+    };
+
+    let mut rng = Rng::new(config.seed);
+    for case in 1..=config.cases {
+        let original = strategy.generate(&mut rng);
+        if !fails(&original) {
+            continue;
+        }
+
+        let mut shrunk = original.clone();
+        let mut shrinks = 0;
+        while shrinks < MAX_SHRINKS {
+            match strategy.shrink(&shrunk).into_iter().find(|v| fails(v)) {
+                Some(simpler) => {
+                    shrunk = simpler;
+                    shrinks += 1;
+                }
+                None => break,
+            }
+        }
+        return Some(Failure {
+            case,
+            original,
+            shrunk,
+            shrinks,
+        });
+    }
+    None
+}
+
+/// Run `f` without the panic hook printing the panics on this thread. The
+/// hook is shared by every thread, and so by tests running alongside this
+/// one, so it is replaced (once) by one that only keeps quiet on threads
+/// that asked it to.
+fn quietly<T>(f: impl FnOnce() -> T) -> T {
+    static INSTALL_HOOK: Once = Once::new();
+    INSTALL_HOOK.call_once(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !QUIET.get() {
+                hook(info);
+            }
+        }));
+    });
+
+    let was_quiet = QUIET.replace(true);
+    let result = f();
+    QUIET.set(was_quiet);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: Config = Config {
+        cases: 200,
+        seed: 2023,
+    };
+
+    fn generate_many<S: Strategy>(strategy: &S) -> Vec<S::Value> {
+        let mut rng = Rng::new(CONFIG.seed);
+        (0..200).map(|_| strategy.generate(&mut rng)).collect()
+    }
+
+    #[test]
+    fn test_generators_stay_in_bounds() {
+        let values = generate_many(&ints(-5..=5i32));
+        assert!(values.iter().all(|v| (-5..=5).contains(v)));
+        assert!(values.contains(&-5) && values.contains(&5));
+
+        let values = generate_many(&strings("ab", 2..=4));
+        assert!(values
+            .iter()
+            .all(|s| (2..=4).contains(&s.len()) && s.chars().all(|c| "ab".contains(c))));
+
+        let values = generate_many(&vecs(ints(0..=9u8), 0..=3));
+        assert!(values
+            .iter()
+            .all(|v| v.len() <= 3 && v.iter().all(|&n| n <= 9)));
+
+        let values = generate_many(&grids(".#", 1..=3, 2..=5));
+        for grid in values {
+            let rows: Vec<&str> = grid.lines().collect();
+            assert!((1..=3).contains(&rows.len()));
+            assert!(rows.iter().all(|r| r.len() == rows[0].len()));
+            assert!((2..=5).contains(&rows[0].len()));
+        }
+    }
+
+    #[test]
+    fn test_check_passes() {
+        check_with(CONFIG, &(ints(0..=100i64), ints(0..=100i64)), |&(a, b)| {
+            a + b >= a
+        });
+    }
+
+    #[test]
+    fn test_shrink_ints() {
+        let failure = find_failure(CONFIG, &ints(0..=1000u32), |&n| n < 50).unwrap();
+        assert_eq!(50, failure.shrunk);
+        assert!(failure.original >= 50);
+
+        // -10 is the simplest value in this range
+        let failure = find_failure(CONFIG, &ints(-1000..=-10i32), |&n| n > -20).unwrap();
+        assert_eq!(-20, failure.shrunk);
+    }
+
+    #[test]
+    fn test_shrink_vecs() {
+        let failure = find_failure(CONFIG, &vecs(ints(0..=9u32), 0..=20), |v| {
+            v.iter().sum::<u32>() < 10
+        })
+        .unwrap();
+        assert_eq!(10, failure.shrunk.iter().sum::<u32>());
+        assert!(failure.shrunk.len() <= 2);
+    }
+
+    #[test]
+    fn test_shrink_strings_and_grids() {
+        let failure = find_failure(CONFIG, &strings("abc", 0..=10), |s| !s.contains('c')).unwrap();
+        assert_eq!("c", failure.shrunk);
+
+        let failure = find_failure(CONFIG, &grids(".#", 1..=5, 1..=5), |g| {
+            g.matches('#').count() < 2
+        })
+        .unwrap();
+        // two cells on a diagonal can't lose a row or column
+        assert_eq!(2, failure.shrunk.matches('#').count());
+        assert!(failure.shrunk.lines().count() <= 2);
+        assert!(failure.shrunk.lines().all(|row| row.len() <= 2));
+    }
+
+    #[test]
+    fn test_panic_is_a_failure() {
+        let failure = find_failure(CONFIG, &vecs(ints(0..=9u8), 0..=5), |v| v[0] < 10);
+        assert_eq!(Vec::<u8>::new(), failure.unwrap().shrunk);
+    }
+
+    #[test]
+    fn test_quietly() {
+        let result = quietly(|| panic::catch_unwind(|| panic!("not printed")));
+        assert!(result.is_err());
+        assert!(!QUIET.get());
+        assert_eq!(3, quietly(|| quietly(|| 3)));
+        assert!(!QUIET.get());
+    }
+
+    #[test]
+    fn test_check_reports_seed() {
+        let result = panic::catch_unwind(|| check_with(CONFIG, &ints(0..=1000u32), |&n| n < 50));
+        let payload = result.unwrap_err();
+        let message = payload.downcast_ref::<String>().unwrap();
+        assert!(message.starts_with("property failed on case "));
+        assert!(message.contains("(seed 2023; rerun with PROPERTY_SEED=2023)"));
+        assert!(message.contains("\n  shrunk: 50 (after "));
+    }
+}
@@ -0,0 +1,160 @@
+use std::{env, fs, io, path::Path};
+
+/// Set this environment variable (to anything) to save the rendered output
+/// as the snapshot rather than comparing against it
+pub const UPDATE_ENV: &str = "UPDATE_SNAPSHOTS";
+
+/// Unchanged lines shown either side of a change in a diff
+const CONTEXT_LINES: usize = 3;
+
+/// Compare `actual` with the snapshot `<name>.snap` in the `snapshots`
+/// directory of the crate at `crate_dir`, panicking with a line diff if
+/// they differ or if there is no snapshot yet. Use `assert_snapshot!`
+/// rather than calling this directly.
+pub fn assert_snapshot(crate_dir: &str, name: &str, actual: &str) {
+    let path = Path::new(crate_dir)
+        .join("snapshots")
+        .join(format!("{}.snap", name));
+    if let Err(message) = check_snapshot(&path, actual, env::var_os(UPDATE_ENV).is_some()) {
+        panic!("{}", message);
+    }
+}
+
+/// Compare `actual` with the snapshot saved at `path`, or if `update` is
+/// set, save `actual` there instead
+fn check_snapshot(path: &Path, actual: &str, update: bool) -> Result<(), String> {
+    if update {
+        return path
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| fs::write(path, actual))
+            .map_err(|e| format!("could not write {}: {}", path.display(), e));
+    }
+
+    match fs::read_to_string(path) {
+        Ok(expected) if expected == actual => Ok(()),
+        Ok(expected) => Err(format!(
+            "snapshot {} does not match (- snapshot, + actual); rerun with {}=1 to accept it\n{}",
+            path.display(),
+            UPDATE_ENV,
+            diff_lines(&expected, actual)
+        )),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(format!(
+            "there is no snapshot {}; rerun with {}=1 to save this output as it\n{}",
+            path.display(),
+            UPDATE_ENV,
+            actual
+        )),
+        Err(e) => Err(format!("could not read {}: {}", path.display(), e)),
+    }
+}
+
+/// Compare a rendered `String` with the snapshot called `name` saved in the
+/// calling crate's `snapshots` directory:
+///
+/// ```ignore
+/// assert_snapshot!("example_path", desert_island_map.draw(&paths, &start, &goal));
+/// ```
+///
+/// Run the tests with `UPDATE_SNAPSHOTS=1` to save new or changed snapshots.
+#[macro_export]
+macro_rules! assert_snapshot {
+    ($name:expr, $actual:expr) => {
+        $crate::snapshot::assert_snapshot(env!("CARGO_MANIFEST_DIR"), $name, &$actual)
+    };
+}
+
+/// A line diff of `expected` against `actual`: removed lines start with
+/// `-`, added lines with `+`, and unchanged lines far from any change are
+/// left out
+pub fn diff_lines(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+
+    // lengths of the longest common subsequences of the remaining lines
+    let mut common = vec![vec![0; actual.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            common[i][j] = if expected[i] == actual[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < expected.len() || j < actual.len() {
+        if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+            lines.push((' ', expected[i]));
+            i += 1;
+            j += 1;
+        } else if i < expected.len() && (j == actual.len() || common[i + 1][j] >= common[i][j + 1])
+        {
+            lines.push(('-', expected[i]));
+            i += 1;
+        } else {
+            lines.push(('+', actual[j]));
+            j += 1;
+        }
+    }
+
+    let is_near_change = |index: usize| {
+        let start = index.saturating_sub(CONTEXT_LINES);
+        let end = (index + CONTEXT_LINES + 1).min(lines.len());
+        lines[start..end].iter().any(|(change, _)| *change != ' ')
+    };
+    let mut diff = String::new();
+    let mut skipped = false;
+    for (index, (change, line)) in lines.iter().enumerate() {
+        if is_near_change(index) {
+            diff.push_str(&format!("{} {}\n", change, line));
+            skipped = false;
+        } else if !skipped {
+            diff.push_str("  ...\n");
+            skipped = true;
+        }
+    }
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process;
+
+    #[test]
+    fn test_diff_lines() {
+        assert_eq!(
+            "  a\n- b\n+ B\n  c\n+ d\n",
+            diff_lines("a\nb\nc\n", "a\nB\nc\nd\n")
+        );
+        assert_eq!(
+            "  ...\n  4\n  5\n  6\n- 7\n  8\n  9\n",
+            diff_lines("1\n2\n3\n4\n5\n6\n7\n8\n9\n", "1\n2\n3\n4\n5\n6\n8\n9\n")
+        );
+        assert_eq!("", diff_lines("", ""));
+    }
+
+    #[test]
+    fn test_check_snapshot() {
+        let snapshots_dir = env::temp_dir()
+            .join(format!("test-support-snapshot-{}", process::id()))
+            .join("snapshots");
+        let path = snapshots_dir.join("grid.snap");
+
+        let missing = check_snapshot(&path, "1> 2v\n3  4>\n", false).unwrap_err();
+        assert!(missing.starts_with("there is no snapshot "));
+        assert!(missing.contains("rerun with UPDATE_SNAPSHOTS=1"));
+
+        assert_eq!(Ok(()), check_snapshot(&path, "1> 2v\n3  4>\n", true));
+        assert_eq!(Ok(()), check_snapshot(&path, "1> 2v\n3  4>\n", false));
+
+        let mismatch = check_snapshot(&path, "1> 2v\n3< 4 \n", false).unwrap_err();
+        assert!(mismatch.contains("grid.snap does not match"));
+        assert!(mismatch.ends_with("  1> 2v\n- 3  4>\n+ 3< 4 \n"));
+
+        fs::remove_dir_all(snapshots_dir.parent().unwrap()).unwrap();
+    }
+}